@@ -1,40 +1,159 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-certification
 
 use super::*;
 use frame::{deps::frame_benchmarking::v2::*, prelude::*};
 
-#[benchmarks]
+#[instance_benchmarks]
 mod benchmarks {
     use super::*;
     #[cfg(test)]
     use crate::pallet::Pallet as Template;
-    use frame_system::RawOrigin;
 
+    /// Creating a certification with `m` metadata entries, a request id, an external id and a
+    /// deposit, superseding an earlier certification of the caller.
     #[benchmark]
-    fn do_something() {
+    fn add_certification(m: Linear<0, { T::MaxMetadataEntries::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
+        let deposit = T::CertificationDeposit::deposit_for(&CertificationKind::Professional);
+        T::Currency::make_free_balance_be(
+            &caller,
+            deposit
+                .saturating_mul(10u32.into())
+                .saturating_add(T::Currency::minimum_balance()),
+        );
+        Pallet::<T, I>::create_certifications_for(&caller, 1);
+        let supersedes = <CertificationsByOwner<T, I>>::get(&caller)[0];
+        let metadata: MetadataOf<T, I> = BoundedVec::truncate_from(
+            (0..m)
+                .map(|i| {
+                    (
+                        BoundedVec::truncate_from(i.to_le_bytes().to_vec()),
+                        BoundedVec::truncate_from([0u8].repeat(T::MaxMetaValueLen::get() as usize)),
+                    )
+                })
+                .collect(),
+        );
+        let external_id =
+            BoundedVec::truncate_from([0u8].repeat(T::MaxExternalIdLen::get() as usize));
+
         #[extrinsic_call]
-        do_something(RawOrigin::Signed(caller), 100);
+        _(
+            frame_system::RawOrigin::Signed(caller.clone()),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Professional,
+            true,
+            metadata,
+            None,
+            None,
+            Some(supersedes),
+            Some([0; 32]),
+            Some(external_id),
+        );
 
-        assert_eq!(
-            Something::<T>::get().map(|v| v.block_number),
-            Some(100u32.into())
+        assert_eq!(Pallet::<T, I>::count_of(&caller), 2);
+    }
+
+    /// Updating the title and description of a certification, which moves its title index
+    /// entry.
+    #[benchmark]
+    fn update_certification() {
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T, I>::create_certifications_for(&caller, 1);
+        let certification_id = <CertificationsByOwner<T, I>>::get(&caller)[0];
+
+        #[extrinsic_call]
+        _(
+            frame_system::RawOrigin::Signed(caller.clone()),
+            certification_id,
+            b"new title".to_vec(),
+            b"new description".to_vec(),
+            0,
+            None,
         );
+
+        let certification = <ListOfCertifications<T, I>>::get(certification_id)
+            .expect("the certification was updated in place");
+        assert_eq!(certification.version, 1);
     }
 
+    /// A scan of `s` certifications, the first `n` of which (at most `s`) are expiring soon
+    /// and get a warning.
     #[benchmark]
-    fn cause_error() {
-        Something::<T>::put(CompositeStruct {
-            block_number: 100u32.into(),
-        });
+    fn process_expirations(
+        s: Linear<0, { T::MaxExpiryChecksPerBlock::get() }>,
+        n: Linear<0, { T::MaxExpiryChecksPerBlock::get() }>,
+    ) {
+        let now: BlockNumberFor<T> = 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(now);
+        for i in 0..s {
+            let owner: T::AccountId = account("owner", i, 0);
+            let expires_at = (i < n).then(|| now + 1u32.into());
+            Pallet::<T, I>::do_add_certification(
+                owner.clone(),
+                owner,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                expires_at,
+                None,
+            )
+            .expect("fresh accounts can create a certification");
+        }
+
+        #[block]
+        {
+            Pallet::<T, I>::on_initialize(now);
+        }
+
+        let warned = <ListOfCertifications<T, I>>::iter_values()
+            .filter(|certification| certification.warned)
+            .count();
+        assert_eq!(warned, n.min(s) as usize);
+    }
+
+    /// Removing the first certification of an owner holding `n`, so the whole owner index is
+    /// scanned and rewritten.
+    #[benchmark]
+    fn remove_certification(n: Linear<1, { T::MaxCertificationsPerOwner::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T, I>::create_certifications_for(&caller, n);
+        let certification_id = <CertificationsByOwner<T, I>>::get(&caller)[0];
+
         #[extrinsic_call]
-        cause_error(RawOrigin::Signed(caller));
+        _(
+            frame_system::RawOrigin::Signed(caller.clone()),
+            certification_id,
+            None,
+        );
+
+        assert!(!<ListOfCertifications<T, I>>::contains_key(
+            certification_id
+        ));
+        assert_eq!(Pallet::<T, I>::count_of(&caller), n - 1);
+    }
+
+    /// Moving all `n` certifications of the caller to an account holding none.
+    #[benchmark]
+    fn transfer_all_certifications(
+        n: Linear<0, { T::MaxTransferPerCall::get().min(T::MaxCertificationsPerOwner::get()) }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        Pallet::<T, I>::create_certifications_for(&caller, n);
 
-        assert_eq!(
-            Something::<T>::get().map(|v| v.block_number),
-            Some(101u32.into())
+        #[extrinsic_call]
+        _(
+            frame_system::RawOrigin::Signed(caller.clone()),
+            new_owner.clone(),
         );
+
+        assert_eq!(Pallet::<T, I>::count_of(&caller), 0);
+        assert_eq!(Pallet::<T, I>::count_of(&new_owner), n);
     }
 
     impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
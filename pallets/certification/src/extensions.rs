@@ -0,0 +1,115 @@
+//! Transaction extensions of the certification pallet.
+
+use crate::{Call, Config, Pallet};
+use codec::DecodeWithMemTracking;
+use core::{fmt, marker::PhantomData};
+use frame::{
+    deps::{
+        frame_support::traits::IsSubType,
+        sp_runtime::{
+            impl_tx_ext_default,
+            traits::{
+                AsSystemOriginSigner, DispatchInfoOf, Dispatchable, Implication,
+                TransactionExtension, ValidateResult,
+            },
+        },
+    },
+    prelude::*,
+};
+
+/// [`InvalidTransaction::Custom`] code of a creation rejected because the future owner already
+/// holds [`Config::MaxCertificationsPerOwner`] certifications.
+pub const QUOTA_EXCEEDED: u8 = 1;
+
+/// Reject certification creations from the transaction pool when the future owner would exceed
+/// [`Config::MaxCertificationsPerOwner`], instead of failing them on dispatch. A batch counts
+/// each of its items.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct CheckCertificationQuota<T: Config<I> + Send + Sync, I: 'static + Send + Sync = ()>(
+    PhantomData<(T, I)>,
+);
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> CheckCertificationQuota<T, I> {
+    /// Create a new instance of the extension.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// The account a call would create certifications for, if any, and how many.
+    ///
+    /// [`Call::upsert_certification`] only creates a certification if its id is not stored yet.
+    fn creations<'a>(
+        call: &'a Call<T, I>,
+        who: &'a T::AccountId,
+    ) -> Option<(&'a T::AccountId, u32)> {
+        match call {
+            Call::add_certification { .. }
+            | Call::claim_certification { .. }
+            | Call::reissue_certification { .. }
+            | Call::clone_certification { .. } => Some((who, 1)),
+            Call::add_certification_for { owner, .. } => Some((owner, 1)),
+            Call::add_certifications { certifications } => Some((who, certifications.len() as u32)),
+            Call::upsert_certification {
+                certification_id, ..
+            } if !Pallet::<T, I>::exists(certification_id) => Some((who, 1)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> Default
+    for CheckCertificationQuota<T, I>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> fmt::Debug
+    for CheckCertificationQuota<T, I>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CheckCertificationQuota")
+    }
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> TransactionExtension<T::RuntimeCall>
+    for CheckCertificationQuota<T, I>
+where
+    T::RuntimeCall: IsSubType<Call<T, I>>,
+    <T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "CheckCertificationQuota";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, _call: &T::RuntimeCall) -> Weight {
+        T::DbWeight::get().reads(1)
+    }
+
+    fn validate(
+        &self,
+        origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        if let (Some(who), Some(call)) = (origin.as_system_origin_signer(), call.is_sub_type()) {
+            if let Some((owner, count)) = Self::creations(call, who) {
+                ensure!(
+                    Pallet::<T, I>::count_of(owner).saturating_add(count)
+                        <= T::MaxCertificationsPerOwner::get(),
+                    InvalidTransaction::Custom(QUOTA_EXCEEDED)
+                );
+            }
+        }
+        Ok((ValidTransaction::default(), (), origin))
+    }
+
+    impl_tx_ext_default!(T::RuntimeCall; prepare);
+}
@@ -49,17 +49,368 @@
 
 pub use pallet::*;
 
-use frame::{prelude::*, traits::Hash};
+use codec::DecodeWithMemTracking;
+use frame::{
+    deps::{
+        frame_support::{
+            dispatch::WithPostDispatchInfo,
+            storage::{unhashed, with_storage_layer},
+            weights::WeightMeter,
+        },
+        frame_system::offchain::{CreateInherent, SubmitTransaction},
+        sp_core::H256,
+        sp_runtime::{offchain::storage::StorageValueRef, WeakBoundedVec},
+    },
+    prelude::*,
+    traits::{
+        CheckedAdd, Currency, Hash, IdentifyAccount, ReservableCurrency, SaturatedConversion,
+        Saturating, Time, Verify, Zero,
+    },
+};
 use scale_info::prelude::vec::Vec;
 
+#[cfg(any(feature = "try-runtime", test))]
+use frame::deps::sp_runtime::TryRuntimeError;
+
+/// Interface for other pallets to create certifications without going through an extrinsic.
+///
+/// Implemented by [`Pallet`], so a dependent pallet can declare
+/// `type CertificationProvider: CertificationIssuer<Self::AccountId, Self::Hash>` and call
+/// `T::CertificationProvider::issue(...)` as a side effect of its own logic.
+pub trait CertificationIssuer<AccountId, Hash> {
+    /// Create a certification owned by `owner` and return its id.
+    fn issue(
+        owner: AccountId,
+        title: Vec<u8>,
+        description: Vec<u8>,
+    ) -> Result<CertificationId<Hash>, DispatchError>;
+}
+
+/// Interface for other pallets to check whether an account holds certifications, e.g. to gate
+/// features behind holding at least one credential.
+///
+/// Implemented by [`Pallet`] in constant time.
+pub trait CertificationInspect<AccountId> {
+    /// Whether `who` owns at least one certification.
+    fn has_any_certification(who: &AccountId) -> bool;
+}
+
+/// What a certification attests, used to price its deposit.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    RuntimeDebug,
+)]
+pub enum CertificationKind {
+    /// A credential verified by a professional body.
+    Professional,
+    /// A degree or course completion.
+    Academic,
+    /// A skill declared by the holder themselves.
+    SelfDeclared,
+    #[default]
+    Other,
+}
+
+/// A change recorded in the [`History`] of a certification.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum HistoryAction {
+    Created,
+    Updated,
+    Revoked,
+    Transferred,
+}
+
+/// How the description of a certification should be rendered.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    RuntimeDebug,
+)]
+pub enum ContentType {
+    #[default]
+    PlainText,
+    Markdown,
+    /// A link to the document, which must be ASCII and start with a URI scheme.
+    Uri,
+}
+
+impl ContentType {
+    /// Whether `description` is valid content of this type.
+    pub fn accepts(&self, description: &[u8]) -> bool {
+        match self {
+            Self::PlainText | Self::Markdown => true,
+            Self::Uri => {
+                let Some(colon) = description.iter().position(|byte| *byte == b':') else {
+                    return false;
+                };
+                let scheme = &description[..colon];
+                description.iter().all(u8::is_ascii_graphic)
+                    && scheme.first().is_some_and(u8::is_ascii_alphabetic)
+                    && scheme
+                        .iter()
+                        .all(|byte| byte.is_ascii_alphanumeric() || b"+-.".contains(byte))
+            }
+        }
+    }
+}
+
+/// Why [`Pallet::try_get_certification`] could not read a certification.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CertificationReadError {
+    /// No certification is stored under the id.
+    NotFound,
+    /// The stored bytes do not decode into the current [`Certification`] layout, e.g. because
+    /// the entry has not been migrated yet.
+    DecodeFailed,
+}
+
+/// Identifier of a certification.
+///
+/// Wraps the runtime hash so that certification ids cannot be mixed up with content hashes or
+/// other hashes. Encodes exactly like the wrapped hash, so it does not change the storage layout.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    RuntimeDebug,
+)]
+pub struct CertificationId<Hash>(pub Hash);
+
+impl<Hash> CertificationId<Hash> {
+    /// The wrapped hash.
+    pub fn into_inner(self) -> Hash {
+        self.0
+    }
+}
+
+impl<Hash> From<Hash> for CertificationId<Hash> {
+    fn from(hash: Hash) -> Self {
+        Self(hash)
+    }
+}
+
+impl From<CertificationId<H256>> for H256 {
+    fn from(id: CertificationId<H256>) -> Self {
+        id.0
+    }
+}
+
+/// Deposit reserved from the owner of a new certification, depending on its kind.
+pub trait KindDeposit<Balance> {
+    /// The amount reserved for a certification of the given kind.
+    fn deposit_for(kind: &CertificationKind) -> Balance;
+}
+
+/// Balance type of the configured [`Config::Currency`].
+pub type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// What [`Pallet::get_certification`] reveals about a certification to a given caller.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertificationDetails<AccountId, Hash, BlockNumber> {
+    pub id: CertificationId<Hash>,
+    pub owner_id: AccountId,
+    pub issuer: AccountId,
+    /// `None` if the certification is private and the caller is not its owner.
+    pub title: Option<Vec<u8>>,
+    /// `None` if the certification is private and the caller is not its owner.
+    pub description: Option<Vec<u8>>,
+    pub public: bool,
+    pub created_at: BlockNumber,
+    pub updated_at: BlockNumber,
+}
+
+/// A flat, non-generic-heavy copy of a stored certification, see [`Pallet::view`].
+///
+/// Block numbers are widened to `u64` so that the encoding stays stable for clients even if
+/// the runtime block number type or the stored struct change.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertificationView<AccountId, Hash> {
+    pub id: Hash,
+    pub owner: AccountId,
+    pub issuer: AccountId,
+    pub title: Vec<u8>,
+    pub description: Vec<u8>,
+    pub content_type: ContentType,
+    pub content_hash: Hash,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub expires_at: Option<u64>,
+    pub schema_version: u16,
+    pub version: u32,
+    pub public: bool,
+    pub frozen: bool,
+    pub transferable: bool,
+    pub revoked: bool,
+}
+
+/// The fields of a certification needed to list it, without its description, see
+/// [`Pallet::certification_headers_of`].
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertificationHeader<AccountId, Hash> {
+    pub id: Hash,
+    pub owner: AccountId,
+    pub title: Vec<u8>,
+    pub created_at: u64,
+}
+
+/// Chain-wide certification statistics, see [`Pallet::stats`].
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertificationStats {
+    /// Number of certifications currently on chain.
+    pub total: u64,
+    /// Number of certifications ever created, i.e. the current [`CertificationNonce`].
+    pub nonce: u64,
+}
+
+/// The configured limits of the pallet, so that clients need not hardcode them, see
+/// [`Pallet::limits`].
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertificationLimits {
+    /// [`Config::MaxCertificationsPerOwner`].
+    pub max_certifications_per_owner: u32,
+    /// [`Config::MaxTransferPerCall`].
+    pub max_transfer_per_call: u32,
+    /// [`Config::MaxCreatesPerWindow`].
+    pub max_creates_per_window: u32,
+    /// [`Config::MaxTotalCertifications`].
+    pub max_total_certifications: u32,
+    /// [`Config::MaxMetaKeyLen`].
+    pub max_meta_key_len: u32,
+    /// [`Config::MaxMetaValueLen`].
+    pub max_meta_value_len: u32,
+    /// [`Config::MaxMetadataEntries`].
+    pub max_metadata_entries: u32,
+    /// [`Config::MaxOperators`].
+    pub max_operators: u32,
+    /// [`Config::MaxOffchainScan`].
+    pub max_offchain_scan: u32,
+    /// [`Config::MaxBatchSize`].
+    pub max_batch_size: u32,
+    /// [`Config::MaxTitleLength`].
+    pub max_title_length: u32,
+    /// [`Config::MinTitleLength`].
+    pub min_title_length: u32,
+    /// [`Config::MaxDescriptionLength`].
+    pub max_description_length: u32,
+    /// [`Config::MaxExpiryChecksPerBlock`].
+    pub max_expiry_checks_per_block: u32,
+    /// [`Config::MaxReasonLen`].
+    pub max_reason_len: u32,
+    /// [`Config::MaxChunkLen`].
+    pub max_chunk_len: u32,
+    /// [`Config::MaxChunks`].
+    pub max_chunks: u32,
+    /// [`Config::MaxOwnersPerQuery`].
+    pub max_owners_per_query: u32,
+    /// [`Config::MaxExternalIdLen`].
+    pub max_external_id_len: u32,
+    /// [`Config::MaxEditors`].
+    pub max_editors: u32,
+}
+
+/// The maximum number of blocks [`Pallet::created_between`] scans; longer ranges are truncated.
+pub const MAX_CREATED_BETWEEN_BLOCKS: u32 = 1_000;
+
+/// Offchain storage key of the [`ListOfCertifications`] key the offchain worker resumes after.
+const OCW_CURSOR_KEY: &[u8] = b"pallet-certification::ocw-cursor";
+
+/// Offchain storage key prefix of the ids of the certifications created in each block, see
+/// [`Pallet::offchain_index_key`].
+pub const OCW_INDEX_PREFIX: &[u8] = b"pallet-certification::created::";
+
+/// Offchain storage key of the last block the offchain worker indexed.
+const OCW_INDEXED_BLOCK_KEY: &[u8] = b"pallet-certification::indexed-block";
+
+/// The maximum number of blocks the offchain worker indexes per run, so that it catches up
+/// gradually after the node was offline.
+pub const MAX_OCW_INDEXED_BLOCKS: u32 = 16;
+
+/// Number of leading title bytes [`TitlesByOwner`] is ordered by.
+pub const TITLE_SORT_KEY_LEN: usize = 16;
+
+/// Moment type of the configured [`Config::Time`] source.
+pub type MomentOf<T, I = ()> = <<T as Config<I>>::Time as Time>::Moment;
+
+/// [`Time`] source for runtimes without a timestamp pallet.
+///
+/// Always reports a zero moment, so no creation time is recorded.
+pub struct NoTime;
+impl Time for NoTime {
+    type Moment = u64;
+
+    fn now() -> Self::Moment {
+        0
+    }
+}
+
+impl<T: Config<I>, I: 'static> CertificationIssuer<T::AccountId, T::Hash> for Pallet<T, I> {
+    fn issue(
+        owner: T::AccountId,
+        title: Vec<u8>,
+        description: Vec<u8>,
+    ) -> Result<CertificationIdOf<T>, DispatchError> {
+        Self::ensure_not_paused()?;
+        Self::do_add_certification(
+            owner.clone(),
+            owner,
+            title,
+            description,
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+        )
+    }
+}
+
+impl<T: Config<I>, I: 'static> CertificationInspect<T::AccountId> for Pallet<T, I> {
+    fn has_any_certification(who: &T::AccountId) -> bool {
+        Pallet::<T, I>::has_any_certification(who)
+    }
+}
+
 #[cfg(test)]
 mod mock;
 
 #[cfg(test)]
 mod tests;
 
+pub mod extensions;
+pub mod migrations;
+pub mod runtime_api;
 pub mod weights;
 
+pub use extensions::CheckCertificationQuota;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -75,235 +426,3896 @@ pub mod pallet {
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>:
+        frame_system::Config + CreateInherent<Call<Self, I>>
+    {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_runtime_types/index.html>
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: crate::weights::WeightInfo;
+
+        /// The maximum number of certifications a single account can own.
+        #[pallet::constant]
+        type MaxCertificationsPerOwner: Get<u32>;
+
+        /// The maximum number of certifications moved by a single
+        /// [`Pallet::transfer_all_certifications`] call.
+        #[pallet::constant]
+        type MaxTransferPerCall: Get<u32>;
+
+        /// The maximum number of certifications an account can create within one window.
+        #[pallet::constant]
+        type MaxCreatesPerWindow: Get<u32>;
+
+        /// The length, in blocks, of a rate limiting window.
+        #[pallet::constant]
+        type WindowLength: Get<BlockNumberFor<Self>>;
+
+        /// Off-chain signature type used by issuers to sign certifications claimed through
+        /// [`Pallet::claim_certification`].
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// Public key type matching [`Config::OffchainSignature`], identifying an account.
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// The schema version assigned to newly created certifications.
+        #[pallet::constant]
+        type CurrentSchemaVersion: Get<u16>;
+
+        /// The origin allowed to perform privileged operations on any certification, such as
+        /// [`Pallet::force_remove_certification`] and [`Pallet::reassign_owner`]. Typically
+        /// `EnsureRoot`, or a collective.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Wall-clock time source, usually the timestamp pallet. Use [`NoTime`] if the runtime
+        /// has none.
+        type Time: Time;
+
+        /// Hasher used for the content hash of certifications, independently of the hasher used
+        /// to derive their ids.
+        type ContentHasher: Hash<Output = Self::Hash>;
+
+        /// The minimum number of blocks between two certifications created by the same account.
+        #[pallet::constant]
+        type CreationCooldown: Get<BlockNumberFor<Self>>;
+
+        /// If true, owner-gated calls fail with [`Error::AccessDenied`] both for missing
+        /// certifications and for certifications owned by someone else, so that callers cannot
+        /// probe which ids exist.
+        #[pallet::constant]
+        type HideExistence: Get<bool>;
+
+        /// If true, removed certifications are moved to [`ArchivedCertifications`] instead of
+        /// being deleted.
+        #[pallet::constant]
+        type ArchiveRemoved: Get<bool>;
+
+        /// The maximum number of certifications that can exist on chain at the same time,
+        /// enforced on every creation path against [`CertificationCount`]. Removals free up
+        /// capacity.
+        #[pallet::constant]
+        type MaxTotalCertifications: Get<u32>;
+
+        /// The maximum length of a metadata key.
+        #[pallet::constant]
+        type MaxMetaKeyLen: Get<u32>;
+
+        /// The maximum length of a metadata value.
+        #[pallet::constant]
+        type MaxMetaValueLen: Get<u32>;
+
+        /// The maximum number of metadata entries per certification.
+        #[pallet::constant]
+        type MaxMetadataEntries: Get<u32>;
+
+        /// If true, [`Event::CertificationStored`] carries the title and description, so that
+        /// indexers following only events can reconstruct the content.
+        #[pallet::constant]
+        type EmitContentInEvents: Get<bool>;
+
+        /// The maximum number of operators an account can authorize.
+        #[pallet::constant]
+        type MaxOperators: Get<u32>;
+
+        /// Certifications expiring within this many blocks are reported as expiring soon by the
+        /// offchain worker.
+        #[pallet::constant]
+        type ExpiryWarningWindow: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of certifications the offchain worker scans per block.
+        #[pallet::constant]
+        type MaxOffchainScan: Get<u32>;
+
+        /// The maximum number of items in a single [`Pallet::verify_certifications_batch`] or
+        /// [`Pallet::add_certifications`] call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// The maximum length of a certification title.
+        #[pallet::constant]
+        type MaxTitleLength: Get<u32>;
+
+        /// The minimum length of a certification title. Should be at least 1, so that empty
+        /// titles are rejected.
+        #[pallet::constant]
+        type MinTitleLength: Get<u32>;
+
+        /// The maximum length of a certification description.
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        /// If true, each owner's entry in [`CertificationsByOwner`] is kept sorted by id, so
+        /// lookups can use binary search. Otherwise ids are kept in insertion order.
+        #[pallet::constant]
+        type SortOwnerIndex: Get<bool>;
+
+        /// The currency deposits are reserved in.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The deposit reserved for each kind of certification, refunded on removal.
+        type CertificationDeposit: KindDeposit<BalanceOf<Self>>;
+
+        /// If true, an owner cannot hold two certifications with the same title, tracked by
+        /// [`TitleIndex`]. Otherwise the index is not maintained at all.
+        #[pallet::constant]
+        type EnforceUniqueTitlePerOwner: Get<bool>;
+
+        /// If true, the id of a new certification is the hash of its owner, title and
+        /// description, so identical content from the same owner cannot be stored twice.
+        /// Otherwise ids are derived from [`CertificationNonce`] and always unique.
+        #[pallet::constant]
+        type ContentAddressed: Get<bool>;
+
+        /// How long a revoked certification is kept before `on_idle` prunes it.
+        #[pallet::constant]
+        type TombstoneRetention: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of certifications `on_initialize` checks for an upcoming expiry
+        /// in each block.
+        #[pallet::constant]
+        type MaxExpiryChecksPerBlock: Get<u32>;
+
+        /// The maximum length of the reason given when removing a certification.
+        #[pallet::constant]
+        type MaxReasonLen: Get<u32>;
+
+        /// The maximum length of each chunk continuing a description, see
+        /// [`DescriptionChunks`].
+        #[pallet::constant]
+        type MaxChunkLen: Get<u32>;
+
+        /// The maximum number of chunks continuing a description.
+        #[pallet::constant]
+        type MaxChunks: Get<u32>;
+
+        /// The maximum number of owners looked up by [`Pallet::certifications_of_many`].
+        #[pallet::constant]
+        type MaxOwnersPerQuery: Get<u32>;
+
+        /// If true, [`Pallet::update_certification`] treats an update as a new issuance and
+        /// resets the creation block and time as well as `updated_at`.
+        #[pallet::constant]
+        type ResetCreatedOnUpdate: Get<bool>;
+
+        /// How long after expiring a certification can still be revived by its owner, see
+        /// [`Pallet::reclaim_certification`].
+        #[pallet::constant]
+        type ReclaimGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum length of the external holder id of a certification, such as a DID.
+        #[pallet::constant]
+        type MaxExternalIdLen: Get<u32>;
+
+        /// The maximum number of editors that can be granted on a single certification.
+        #[pallet::constant]
+        type MaxEditors: Get<u32>;
+
+        /// The number of entries in each page of the [`History`] of a certification. Once a page
+        /// is full, further changes are recorded in the next one and the page before the full
+        /// one is dropped, so at least this many of the latest changes are kept.
+        #[pallet::constant]
+        type MaxHistoryEntries: Get<u32>;
+
+        /// The maximum number of certifications that can be merged into a single one, see
+        /// [`MergedFrom`].
+        #[pallet::constant]
+        type MaxMergedFrom: Get<u32>;
+
+        /// If true, every change to [`CertificationsByOwner`] emits
+        /// [`Event::OwnerIndexAdded`] or [`Event::OwnerIndexRemoved`], for indexers keeping
+        /// denormalized views.
+        #[pallet::constant]
+        type EmitIndexEvents: Get<bool>;
     }
 
+    /// The in-code storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
-    #[pallet::without_storage_info]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
 
     /// Certification struct
     /// Information that is mutable by user
     /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_storage_derives/index.html>
-    #[derive(Encode, Decode, TypeInfo, CloneNoBound, PartialEqNoBound, EqNoBound)]
+    #[derive(
+        Encode, Decode, MaxEncodedLen, TypeInfo, CloneNoBound, PartialEqNoBound, EqNoBound,
+    )]
     pub struct Certification<
         AccountId: Clone + PartialEq + Eq,
         Hash: Clone + PartialEq + Eq,
         BlockNumber: Clone + PartialEq + Eq,
+        Moment: Clone + PartialEq + Eq,
+        Title: Clone + PartialEq + Eq,
+        Description: Clone + PartialEq + Eq,
+        Reason: Clone + PartialEq + Eq,
     > {
-        pub(crate) id: Hash,
+        pub(crate) id: CertificationId<Hash>,
         pub(crate) owner_id: AccountId,
-        pub(crate) title: Vec<u8>,
-        pub(crate) description: Vec<u8>,
+        pub(crate) issuer: AccountId,
+        pub(crate) title: Title,
+        pub(crate) description: Description,
+        /// Hash of the title and description, see [`Pallet::content_hash_of`].
+        pub(crate) content_hash: Hash,
         pub(crate) created_at: BlockNumber,
+        /// Wall-clock creation time, if the runtime has a time source.
+        pub(crate) created_time: Option<Moment>,
         pub(crate) updated_at: BlockNumber,
+        /// Format version of the certification content, so clients know how to parse it.
+        pub(crate) schema_version: u16,
+        /// Incremented on every update, for optimistic concurrency control.
+        pub(crate) version: u32,
+        /// Whether the title and description are readable by anyone, or only by the owner.
+        pub(crate) public: bool,
+        /// Last block at which the certification is valid, if it expires at all.
+        pub(crate) expires_at: Option<BlockNumber>,
+        /// Set once [`Event::CertificationExpiringSoon`] was emitted for the current expiry.
+        pub(crate) warned: bool,
+        /// Set while the certification is under dispute, blocking updates, transfers and
+        /// removal.
+        pub(crate) frozen: bool,
+        /// If false, the certification is bound to its owner and cannot be transferred.
+        pub(crate) transferable: bool,
+        /// The certification this one replaces, see [`Pallet::reissue_certification`].
+        pub(crate) supersedes: Option<CertificationId<Hash>>,
+        /// The certification that replaced this one, if any.
+        pub(crate) superseded_by: Option<CertificationId<Hash>>,
+        /// Set once the certification has been superseded and is no longer valid.
+        pub(crate) revoked: bool,
+        /// What the certification attests, which prices its deposit.
+        pub(crate) kind: CertificationKind,
+        /// How the description should be rendered.
+        pub(crate) content_type: ContentType,
+        /// Why the issuer revoked the certification, see [`Pallet::revoke_certification`].
+        pub(crate) revocation_reason: Option<Reason>,
     }
     impl<
             AccountId: Clone + PartialEq + Eq,
             Hash: Clone + PartialEq + Eq,
             BlockNumber: Clone + PartialEq + Eq,
-        > Certification<AccountId, Hash, BlockNumber>
+            Moment: Clone + PartialEq + Eq,
+            Title: Clone + PartialEq + Eq,
+            Description: Clone + PartialEq + Eq,
+            Reason: Clone + PartialEq + Eq,
+        > Certification<AccountId, Hash, BlockNumber, Moment, Title, Description, Reason>
     {
+        #[allow(clippy::too_many_arguments)]
         pub(crate) fn new(
-            id: Hash,
+            id: CertificationId<Hash>,
             owner_id: AccountId,
-            title: Vec<u8>,
-            description: Vec<u8>,
+            issuer: AccountId,
+            title: Title,
+            description: Description,
+            content_hash: Hash,
             created_at: BlockNumber,
+            created_time: Option<Moment>,
             updated_at: BlockNumber,
+            schema_version: u16,
+            version: u32,
+            public: bool,
+            expires_at: Option<BlockNumber>,
+            warned: bool,
+            frozen: bool,
+            transferable: bool,
+            supersedes: Option<CertificationId<Hash>>,
+            superseded_by: Option<CertificationId<Hash>>,
+            revoked: bool,
+            kind: CertificationKind,
+            content_type: ContentType,
         ) -> Self {
             Self {
                 id,
                 owner_id,
+                issuer,
                 title,
                 description,
+                content_hash,
                 created_at,
+                created_time,
                 updated_at,
+                schema_version,
+                version,
+                public,
+                expires_at,
+                warned,
+                frozen,
+                transferable,
+                supersedes,
+                superseded_by,
+                revoked,
+                kind,
+                content_type,
+                revocation_reason: None,
             }
         }
 
         pub(crate) fn get_owner_id(&self) -> &AccountId {
             &self.owner_id
         }
+
+        /// Whether the certification is held by the account that issued it.
+        pub fn is_self_issued(&self) -> bool {
+            self.issuer == self.owner_id
+        }
+    }
+
+    /// [`CertificationId`] for a given runtime.
+    pub type CertificationIdOf<T> = CertificationId<<T as frame_system::Config>::Hash>;
+
+    /// [`Certification`] as stored by the pallet for a given runtime.
+    pub type CertificationOf<T, I = ()> = Certification<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+        MomentOf<T, I>,
+        TitleOf<T, I>,
+        DescriptionOf<T, I>,
+        ReasonOf<T, I>,
+    >;
+
+    /// The title of a certification.
+    ///
+    /// Weakly bounded so that certifications stored under a higher [`Config::MaxTitleLength`]
+    /// still decode after the limit is lowered.
+    pub type TitleOf<T, I = ()> = WeakBoundedVec<u8, <T as Config<I>>::MaxTitleLength>;
+
+    /// The description of a certification, weakly bounded like [`TitleOf`].
+    pub type DescriptionOf<T, I = ()> = WeakBoundedVec<u8, <T as Config<I>>::MaxDescriptionLength>;
+
+    /// A metadata key of a certification.
+    pub type MetadataKeyOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxMetaKeyLen>;
+
+    /// A metadata value of a certification.
+    pub type MetadataValueOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxMetaValueLen>;
+
+    /// Why a certification was removed or revoked. Only stored for revocations.
+    pub type ReasonOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxReasonLen>;
+
+    /// An identifier of the holder of a certification outside the chain, such as a DID.
+    pub type ExternalIdOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxExternalIdLen>;
+
+    /// Metadata entries attached to a certification at creation.
+    pub type MetadataOf<T, I = ()> = BoundedVec<
+        (MetadataKeyOf<T, I>, MetadataValueOf<T, I>),
+        <T as Config<I>>::MaxMetadataEntries,
+    >;
+
+    /// A removed certification kept for compliance, see [`Config::ArchiveRemoved`].
+    #[derive(
+        Encode, Decode, MaxEncodedLen, TypeInfo, CloneNoBound, PartialEqNoBound, EqNoBound,
+    )]
+    #[scale_info(skip_type_params(T, I))]
+    #[codec(mel_bound(T: Config<I>, I: 'static))]
+    pub struct ArchivedCertification<T: Config<I>, I: 'static = ()> {
+        pub(crate) certification: CertificationOf<T, I>,
+        pub(crate) removed_at: BlockNumberFor<T>,
     }
 
     /// The pallet's storage items.
     /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#storage>
     /// <https://paritytech.github.io/polkadot-sdk/master/frame_support/pallet_macros/attr.storage.html>
     #[pallet::storage]
-    pub type ListOfCertifications<T: Config> = StorageMap<
+    pub type ListOfCertifications<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, CertificationOf<T, I>>;
+
+    /// Monotonic counter mixed into the id of every new certification so that ids are unique
+    /// even when the same account creates several certifications.
+    #[pallet::storage]
+    pub type CertificationNonce<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// The number of certifications currently stored in [`ListOfCertifications`].
+    #[pallet::storage]
+    pub type CertificationCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of certifications owned by each account, kept in sync with
+    /// [`CertificationsByOwner`] so it can be read without decoding the ids.
+    #[pallet::storage]
+    pub type CertificationCountByOwner<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Number of certifications held by each account that it also issued, see
+    /// [`Certification::is_self_issued`].
+    #[pallet::storage]
+    pub type SelfIssuedCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Ids of the certifications owned by each account, in insertion order or sorted, see
+    /// [`Config::SortOwnerIndex`].
+    #[pallet::storage]
+    pub type CertificationsByOwner<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<CertificationIdOf<T>, T::MaxCertificationsPerOwner>,
+        ValueQuery,
+    >;
+
+    /// Ids of the certifications created in each block.
+    #[pallet::storage]
+    pub type CertificationsByBlock<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Numbered chunks continuing the description of each certification, for documents longer
+    /// than [`Config::MaxDescriptionLength`]. See [`Pallet::reassembled_description`].
+    #[pallet::storage]
+    pub type DescriptionChunks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        Twox64Concat,
+        u16,
+        BoundedVec<u8, T::MaxChunkLen>,
+    >;
+
+    /// The external holder id given to a certification at creation, if any.
+    #[pallet::storage]
+    pub type ExternalIds<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, ExternalIdOf<T, I>>;
+
+    /// Certifications by external holder id, see [`Pallet::certifications_by_external_id`].
+    #[pallet::storage]
+    pub type CertificationsByExternalId<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ExternalIdOf<T, I>,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        (),
+    >;
+
+    /// Domain-specific key-value pairs of each certification, such as a grade or an institution
+    /// code. At most [`Config::MaxMetadataEntries`] keys per certification.
+    #[pallet::storage]
+    pub type Metadata<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        Blake2_128Concat,
+        MetadataKeyOf<T, I>,
+        MetadataValueOf<T, I>,
+    >;
+
+    /// Client-supplied request ids already used by each account with
+    /// [`Pallet::add_certification`], and the id of the certification each one created.
+    ///
+    /// An entry is removed along with its certification, see [`CertificationRequests`].
+    #[pallet::storage]
+    pub type SeenRequests<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        [u8; 32],
+        CertificationIdOf<T>,
+    >;
+
+    /// Hashes of the [`Pallet::claim_payload`]s already used with
+    /// [`Pallet::claim_certification`], so that each issuer signature is only redeemed once.
+    #[pallet::storage]
+    pub type ConsumedClaims<T: Config<I>, I: 'static = ()> = StorageMap<_, Identity, T::Hash, ()>;
+
+    /// The account and request id each certification was created with, to clear its
+    /// [`SeenRequests`] entry on removal.
+    #[pallet::storage]
+    pub type CertificationRequests<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, (T::AccountId, [u8; 32])>;
+
+    /// The account each certification's deposit was reserved from, and the amount.
+    #[pallet::storage]
+    pub type CertificationDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, (T::AccountId, BalanceOf<T, I>)>;
+
+    /// Titles held by each owner, by hash, when [`Config::EnforceUniqueTitlePerOwner`] is set.
+    #[pallet::storage]
+    pub type TitleIndex<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, T::Hash, ()>;
+
+    /// The certifications of each owner, keyed by the first [`TITLE_SORT_KEY_LEN`] bytes of
+    /// their title padded with zeros, then by id. Keys are not hashed, so iterating an owner's
+    /// entries visits them in alphabetical order, see [`Pallet::titles_sorted`].
+    #[pallet::storage]
+    pub type TitlesByOwner<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        ([u8; TITLE_SORT_KEY_LEN], CertificationIdOf<T>),
+        (),
+    >;
+
+    /// Accounts allowed to create certifications on behalf of each owner, see
+    /// [`Pallet::add_certification_for`].
+    #[pallet::storage]
+    pub type Operators<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxOperators>,
+        ValueQuery,
+    >;
+
+    /// Start of the current rate limiting window and the number of certifications created in it,
+    /// per account.
+    #[pallet::storage]
+    pub type CreationWindow<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), ValueQuery>;
+
+    /// Certifications removed while [`Config::ArchiveRemoved`] was set, until purged.
+    #[pallet::storage]
+    pub type ArchivedCertifications<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, ArchivedCertification<T, I>>;
+
+    /// Pending transfer offers, from certification id to the account that may accept it.
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, T::AccountId>;
+
+    /// Log of the latest changes made to each certification, oldest first, in pages of
+    /// [`Config::MaxHistoryEntries`] entries, see [`Pallet::history`]. Only the two most recent
+    /// pages are kept.
+    ///
+    /// The log is removed along with the certification.
+    #[pallet::storage]
+    pub type History<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        Twox64Concat,
+        u32,
+        BoundedVec<(BlockNumberFor<T>, HistoryAction), T::MaxHistoryEntries>,
+        ValueQuery,
+    >;
+
+    /// The page of the [`History`] of each certification new changes are appended to.
+    #[pallet::storage]
+    pub type HistoryHead<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, u32, ValueQuery>;
+
+    /// Certifications frozen by [`Pallet::transfer_and_freeze`], which their owner may unfreeze
+    /// without [`Config::ForceOrigin`].
+    #[pallet::storage]
+    pub type EscrowFrozen<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, ()>;
+
+    /// The title and description of each certification merged into another by
+    /// [`Pallet::merge_certifications`], by target id, then source id. At most
+    /// [`Config::MaxMergedFrom`] per target.
+    ///
+    /// Entries are removed along with the target.
+    #[pallet::storage]
+    pub type MergedFrom<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        (TitleOf<T, I>, DescriptionOf<T, I>),
+    >;
+
+    /// Accounts allowed by the owner to edit a certification, see [`Pallet::grant_editor`].
+    /// At most [`Config::MaxEditors`] per certification.
+    #[pallet::storage]
+    pub type Editors<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CertificationIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+    >;
+
+    /// Ids of the certifications of each kind.
+    #[pallet::storage]
+    pub type CertificationsByKind<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
+        Twox64Concat,
+        CertificationKind,
         Blake2_128Concat,
-        T::Hash,
-        Certification<T::AccountId, T::Hash, BlockNumberFor<T>>,
+        CertificationIdOf<T>,
+        (),
     >;
 
+    /// Present while [`CertificationsByKind`] is being rebuilt by `on_idle`, holding the last
+    /// certification indexed so far, if any. See [`crate::migrations::v1::RebuildKindIndex`].
+    #[pallet::storage]
+    pub type KindIndexCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Option<CertificationIdOf<T>>>;
+
+    /// The last certification checked for an upcoming expiry by `on_initialize`, so that the
+    /// next block resumes after it.
+    #[pallet::storage]
+    pub type ExpiryCheckCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, CertificationIdOf<T>>;
+
+    /// The last entry of [`RevokedAt`] checked for pruning by `on_idle`, so that the next block
+    /// resumes after it.
+    #[pallet::storage]
+    pub type PruneCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, CertificationIdOf<T>>;
+
+    /// Block at which each revoked certification was revoked, pending pruning in `on_idle`.
+    #[pallet::storage]
+    pub type RevokedAt<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, CertificationIdOf<T>, BlockNumberFor<T>>;
+
+    /// Whether the pallet is paused by [`Config::ForceOrigin`], rejecting every state-changing
+    /// call except the [`Config::ForceOrigin`] ones.
+    #[pallet::storage]
+    pub type Paused<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    /// Block at which each account last created a certification.
+    #[pallet::storage]
+    pub type LastCreatedAt<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The certification most recently created for each account, by any creation path, so
+    /// clients can learn its id without parsing events. It may since have been removed or
+    /// transferred.
+    #[pallet::storage]
+    pub type LastCreated<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CertificationIdOf<T>>;
+
     /// Pallets use events to inform users when important changes are made.
     /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#event-and-error>
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// We usually use passive tense for events.
         CertificationStored {
+            /// The holder, as in `holder`. Kept for clients predating `issuer` and `holder`.
             who: T::AccountId,
-            certification_id: T::Hash,
+            /// The account that issued the certification.
+            issuer: T::AccountId,
+            /// The account holding the certification.
+            holder: T::AccountId,
+            certification_id: CertificationIdOf<T>,
             created_at: BlockNumberFor<T>,
+            schema_version: u16,
+            /// Only set if [`Config::EmitContentInEvents`] is enabled.
+            title: Option<Vec<u8>>,
+            /// Only set if [`Config::EmitContentInEvents`] is enabled.
+            description: Option<Vec<u8>>,
         },
         CertificationUpdated {
             who: T::AccountId,
-            certification_id: T::Hash,
+            certification_id: CertificationIdOf<T>,
             updated_at: BlockNumberFor<T>,
         },
         CertificationRemoved {
             who: T::AccountId,
-            certification_id: T::Hash,
+            certification_id: CertificationIdOf<T>,
+            reason: Option<ReasonOf<T, I>>,
         },
-    }
-
-    /// Errors inform users that something went wrong.
-    /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#event-and-error>
-    #[pallet::error]
-    pub enum Error<T> {
-        /// The caller is not the owner of the certification.
-        NotOwner,
-        /// Certification not found.
-        CertificationNotFound,
-    }
-
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
-
-    /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-    /// These functions materialize as "extrinsics", which are often compared to transactions.
-    /// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-    /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#dispatchables>
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// An example dispatchable that takes a singles value as a parameter, writes the value to
-        /// storage and emits an event. This function must be dispatched by a signed extrinsic.
-        #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-        pub fn add_certification(
-            origin: OriginFor<T>,
-            title: Vec<u8>,
-            description: Vec<u8>,
-        ) -> DispatchResultWithPostInfo {
-            // Check that the extrinsic was signed and get the signer.
-            // This function will return an error if the extrinsic is not signed.
-            // <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_origin/index.html>
-            let who = ensure_signed(origin)?;
-
-            // Convert the u32 into a block number. This is possible because the set of trait bounds
-            // defined in [`frame_system::Config::BlockNumber`].
-            let block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+        CertificationTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            certification_id: CertificationIdOf<T>,
+        },
+        SchemaVersionUpdated {
+            certification_id: CertificationIdOf<T>,
+            schema_version: u16,
+        },
+        ArchivePurged {
+            removed: u32,
+        },
+        VisibilityChanged {
+            certification_id: CertificationIdOf<T>,
+            public: bool,
+        },
+        TransferOffered {
+            certification_id: CertificationIdOf<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        TransferAccepted {
+            certification_id: CertificationIdOf<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        TransferCancelled {
+            certification_id: CertificationIdOf<T>,
+        },
+        OperatorAuthorized {
+            owner: T::AccountId,
+            operator: T::AccountId,
+        },
+        OperatorRevoked {
+            owner: T::AccountId,
+            operator: T::AccountId,
+        },
+        CertificationFrozen {
+            certification_id: CertificationIdOf<T>,
+        },
+        CertificationUnfrozen {
+            certification_id: CertificationIdOf<T>,
+        },
+        CertificationReissued {
+            old_id: CertificationIdOf<T>,
+            new_id: CertificationIdOf<T>,
+        },
+        /// The issuer revoked a certification.
+        CertificationRevoked {
+            who: T::AccountId,
+            certification_id: CertificationIdOf<T>,
+            reason: Option<ReasonOf<T, I>>,
+        },
+        /// `new` was created to replace `old`.
+        CertificationSuperseded {
+            old: CertificationIdOf<T>,
+            new: CertificationIdOf<T>,
+        },
+        /// Whether each item of a [`Pallet::verify_certifications_batch`] call matched, in
+        /// order.
+        BatchVerified {
+            results: BoundedVec<bool, T::MaxBatchSize>,
+        },
+        CertificationRenewed {
+            certification_id: CertificationIdOf<T>,
+            new_valid_until: BlockNumberFor<T>,
+        },
+        /// An expired certification was revived by its owner until `new_valid_until`.
+        CertificationReclaimed {
+            certification_id: CertificationIdOf<T>,
+            new_valid_until: BlockNumberFor<T>,
+        },
+        CertificationExpiringSoon {
+            certification_id: CertificationIdOf<T>,
+            expires_at: BlockNumberFor<T>,
+        },
+        MetadataSet {
+            certification_id: CertificationIdOf<T>,
+            key: MetadataKeyOf<T, I>,
+        },
+        MetadataCleared {
+            certification_id: CertificationIdOf<T>,
+            key: MetadataKeyOf<T, I>,
+        },
+        /// `count` certifications were moved at once by [`Pallet::transfer_all_certifications`].
+        AllCertificationsTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            count: u32,
+        },
+        /// A certification was removed by [`Config::ForceOrigin`].
+        CertificationForceRemoved {
+            certification_id: CertificationIdOf<T>,
+            owner: T::AccountId,
+            reason: Option<ReasonOf<T, I>>,
+        },
+        /// A certification was given to a new owner by [`Config::ForceOrigin`].
+        CertificationOwnerReassigned {
+            certification_id: CertificationIdOf<T>,
+            old_owner: T::AccountId,
+            new_owner: T::AccountId,
+        },
+        /// A certification revoked for longer than [`Config::TombstoneRetention`] was removed.
+        CertificationPruned {
+            certification_id: CertificationIdOf<T>,
+        },
+        /// `source` was merged into `target` and removed.
+        CertificationsMerged {
+            target: CertificationIdOf<T>,
+            source: CertificationIdOf<T>,
+        },
+        /// `new_id` was created as a copy of `source_id`.
+        CertificationCloned {
+            source_id: CertificationIdOf<T>,
+            new_id: CertificationIdOf<T>,
+        },
+        /// A certification was added to the owner index of `owner`. Only emitted if
+        /// [`Config::EmitIndexEvents`] is set.
+        OwnerIndexAdded {
+            owner: T::AccountId,
+            certification_id: CertificationIdOf<T>,
+        },
+        /// A certification was removed from the owner index of `owner`. Only emitted if
+        /// [`Config::EmitIndexEvents`] is set.
+        OwnerIndexRemoved {
+            owner: T::AccountId,
+            certification_id: CertificationIdOf<T>,
+        },
+        /// The owner allowed `editor` to edit a certification.
+        EditorGranted {
+            certification_id: CertificationIdOf<T>,
+            editor: T::AccountId,
+        },
+        /// The owner withdrew the right of `editor` to edit a certification.
+        EditorRevoked {
+            certification_id: CertificationIdOf<T>,
+            editor: T::AccountId,
+        },
+        /// A chunk continuing the description of a certification was written.
+        DescriptionChunkAppended {
+            certification_id: CertificationIdOf<T>,
+            index: u16,
+        },
+        /// The content type of a certification's description was changed by its owner.
+        ContentTypeSet {
+            certification_id: CertificationIdOf<T>,
+            content_type: ContentType,
+        },
+        /// The pallet was paused or unpaused by [`Config::ForceOrigin`].
+        PalletPausedSet {
+            paused: bool,
+        },
+    }
 
-            // Update storage.
-            <ListOfCertifications<T>>::insert(
-                T::Hashing::hash_of(&who),
-                Certification::new(
-                    T::Hashing::hash_of(&who),
-                    who.clone(),
-                    title,
-                    description,
-                    block_number,
-                    block_number,
-                ),
+    /// Errors inform users that something went wrong.
+    /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#event-and-error>
+    #[pallet::error]
+    pub enum Error<T, I = ()> {
+        /// The caller is not the owner of the certification.
+        NotOwner,
+        /// The caller is not the issuer of the certification.
+        NotIssuer,
+        /// Certification not found.
+        CertificationNotFound,
+        /// The id is already taken. For a derived id this points to a bug in the id derivation,
+        /// or to a certification created under that id by [`Pallet::upsert_certification`].
+        IdCollision,
+        /// A certification with the same owner and content already exists, see
+        /// [`Config::ContentAddressed`].
+        CertificationAlreadyExists,
+        /// The account already owns the maximum number of certifications.
+        TooManyCertifications,
+        /// The caller owns more certifications than can be transferred in a single call.
+        TooManyToTransfer,
+        /// The account created too many certifications in the current window.
+        RateLimited,
+        /// The signature does not match the claimed certification and issuer.
+        InvalidSignature,
+        /// The issuer signature was already redeemed with [`Pallet::claim_certification`].
+        ClaimAlreadyUsed,
+        /// The account created a certification too recently.
+        CreationTooFrequent,
+        /// The certification does not exist or is not owned by the caller.
+        AccessDenied,
+        /// The certification was updated since the caller last read it.
+        VersionMismatch,
+        /// The certification was updated after the block the caller expected.
+        StaleUpdate,
+        /// The certification has no pending transfer offer.
+        NoPendingTransfer,
+        /// The caller is not the recipient of the pending transfer offer.
+        NotTransferRecipient,
+        /// The chain already holds [`Config::MaxTotalCertifications`] certifications.
+        GlobalCapReached,
+        /// The certification has no metadata entry with the given key.
+        MetadataKeyNotFound,
+        /// The certification already has [`Config::MaxMetadataEntries`] metadata entries.
+        TooManyMetadataEntries,
+        /// The caller already submitted a certification with this request id, see
+        /// [`SeenRequests`] for the certification it created.
+        DuplicateRequest,
+        /// The caller is not an operator of the owner.
+        NotAuthorizedOperator,
+        /// The operator is already authorized by the owner.
+        OperatorAlreadyAuthorized,
+        /// The owner already has [`Config::MaxOperators`] operators.
+        TooManyOperators,
+        /// The expiry block is not in the future, or both an expiry block and a duration were
+        /// given.
+        InvalidExpiry,
+        /// The expiry computed from a duration does not fit in a block number.
+        ExpiryOverflow,
+        /// A renewal must move the expiry strictly later.
+        RenewalNotExtending,
+        /// The certification is soulbound and cannot change owner.
+        NotTransferable,
+        /// The certification is frozen and cannot be updated, transferred or removed.
+        CertificationFrozen,
+        /// The certification has been revoked.
+        CertificationRevoked,
+        /// The title is longer than [`Config::MaxTitleLength`].
+        TitleTooLong,
+        /// The title is shorter than [`Config::MinTitleLength`].
+        TitleTooShort,
+        /// The description is longer than [`Config::MaxDescriptionLength`].
+        DescriptionTooLong,
+        /// The certification does not expire, so it cannot be renewed.
+        NotRenewable,
+        /// The certification has not expired yet, so it can be renewed rather than reclaimed.
+        NotExpired,
+        /// The certification expired more than [`Config::ReclaimGracePeriod`] ago.
+        ReclaimWindowClosed,
+        /// The certification to supersede does not exist or is not owned by the caller.
+        InvalidSupersedeTarget,
+        /// The owner already holds a certification with this title.
+        DuplicateTitleForOwner,
+        /// The pallet is paused, see [`Paused`].
+        PalletPaused,
+        /// A certification cannot be merged into itself.
+        CannotMergeWithSelf,
+        /// [`Config::MaxMergedFrom`] certifications were already merged into the target.
+        TooManyMerges,
+        /// The description is not a URI, as required by [`ContentType::Uri`].
+        InvalidUri,
+        /// The chunk index is not below [`Config::MaxChunks`].
+        TooManyChunks,
+        /// The chunk index would leave a gap after the existing chunks.
+        ChunkIndexGap,
+        /// The stored certification does not decode into the current layout, e.g. because it
+        /// has not been migrated yet.
+        CorruptedEntry,
+        /// The caller is neither the owner nor an editor of the certification.
+        NotAuthorized,
+        /// The certification already has [`Config::MaxEditors`] editors.
+        TooManyEditors,
+        /// The account is already an editor of the certification.
+        AlreadyEditor,
+        /// The account is not an editor of the certification.
+        NotEditor,
+        /// The owner of a certification cannot also be one of its editors.
+        OwnerIsEditor,
+        /// The caller cannot reserve the [`Config::CertificationDeposit`] for a certification
+        /// of this kind.
+        InsufficientDeposit,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        /// Check the next [`Config::MaxExpiryChecksPerBlock`] certifications and emit
+        /// [`Event::CertificationExpiringSoon`] once for each that entered the final
+        /// [`Config::ExpiryWarningWindow`] blocks before its expiry.
+        ///
+        /// The returned weight, which the runtime registers as consumed by the block, is
+        /// [`WeightInfo::process_expirations`] for the number of certifications scanned and
+        /// the number of warnings emitted, so blocks without warnings still pay for the scan.
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let limit = T::MaxExpiryChecksPerBlock::get();
+            let start = <ExpiryCheckCursor<T, I>>::get()
+                .map(|id| <ListOfCertifications<T, I>>::hashed_key_for(id));
+            let (expiring, last, scanned) = Self::scan_expiring(start, now, limit);
+            match last {
+                Some(id) => <ExpiryCheckCursor<T, I>>::put(id),
+                None => <ExpiryCheckCursor<T, I>>::kill(),
+            }
+            for certification_id in &expiring {
+                Self::warn_expiring(certification_id, now);
+            }
+
+            T::WeightInfo::process_expirations(scanned, expiring.len() as u32)
+        }
+
+        /// Continue rebuilding [`CertificationsByKind`] if a rebuild is pending, then prune
+        /// certifications revoked for longer than [`Config::TombstoneRetention`], as many as
+        /// `remaining_weight` allows.
+        ///
+        /// Each prune is charged [`Pallet::remove_weight`] for a full owner index. The scan
+        /// resumes from [`PruneCursor`], so that entries late in [`RevokedAt`] are reached even
+        /// when each block only has room for a few.
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::rebuild_kind_index(&mut meter);
+            // Reserve reading and updating the cursor.
+            if meter
+                .try_consume(T::DbWeight::get().reads_writes(1, 1))
+                .is_err()
+            {
+                return meter.consumed();
+            }
+            let read = T::DbWeight::get().reads(1);
+            let prune = Self::remove_weight(T::MaxCertificationsPerOwner::get());
+            let retention = T::TombstoneRetention::get();
+
+            let entries = match <PruneCursor<T, I>>::get() {
+                Some(last) => <RevokedAt<T, I>>::iter_from(<RevokedAt<T, I>>::hashed_key_for(last)),
+                None => <RevokedAt<T, I>>::iter(),
+            };
+            let mut expired = Vec::new();
+            let mut last = None;
+            let mut exhausted = true;
+            for (certification_id, revoked_at) in entries {
+                if meter.try_consume(read).is_err() {
+                    exhausted = false;
+                    break;
+                }
+                if now.saturating_sub(revoked_at) > retention {
+                    if meter.try_consume(prune).is_err() {
+                        exhausted = false;
+                        break;
+                    }
+                    expired.push(certification_id);
+                }
+                last = Some(certification_id);
+            }
+            if exhausted {
+                <PruneCursor<T, I>>::kill();
+            } else if let Some(id) = last {
+                <PruneCursor<T, I>>::put(id);
+            }
+
+            for certification_id in expired {
+                match <ListOfCertifications<T, I>>::get(certification_id) {
+                    Some(certification) => {
+                        Self::do_remove_certification(certification_id, certification);
+                        Self::deposit_event(Event::CertificationPruned { certification_id });
+                    }
+                    None => <RevokedAt<T, I>>::remove(certification_id),
+                }
+            }
+
+            meter.consumed()
+        }
+
+        /// Index the certifications created since the last run, see
+        /// [`Pallet::index_created_certifications`].
+        ///
+        /// Then scan the next [`Config::MaxOffchainScan`] certifications and report those
+        /// expiring within [`Config::ExpiryWarningWindow`] through [`Pallet::report_expiring`].
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            Self::index_created_certifications(now);
+
+            let cursor = StorageValueRef::persistent(OCW_CURSOR_KEY);
+            let start = cursor.get::<Vec<u8>>().ok().flatten();
+            let (expiring, last, _) = Self::scan_expiring(start, now, T::MaxOffchainScan::get());
+            match last {
+                Some(id) => cursor.set(&<ListOfCertifications<T, I>>::hashed_key_for(id)),
+                None => cursor.clear(),
+            }
+
+            if expiring.is_empty() {
+                return;
+            }
+            log::info!(
+                target: "runtime::certification",
+                "{} certification(s) expiring soon at block {:?}",
+                expiring.len(),
+                now,
             );
+            let call = Call::report_expiring {
+                certification_ids: BoundedVec::truncate_from(expiring),
+            };
+            let xt = T::create_inherent(call.into());
+            if SubmitTransaction::<T, Call<T, I>>::submit_transaction(xt).is_err() {
+                log::error!(
+                    target: "runtime::certification",
+                    "failed to submit expiring certifications report",
+                );
+            }
+        }
 
-            // Emit an event.
-            Self::deposit_event(Event::CertificationStored {
-                who: who.clone(),
-                certification_id: T::Hashing::hash_of(&who),
-                created_at: block_number,
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+        type Call = Call<T, I>;
+
+        /// Only accept [`Pallet::report_expiring`] calls listing certifications that are still
+        /// expiring soon.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::report_expiring { certification_ids } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            let all_expiring = certification_ids.iter().all(|id| {
+                <ListOfCertifications<T, I>>::get(id)
+                    .is_some_and(|certification| Self::needs_expiry_warning(&certification, now))
             });
+            if certification_ids.is_empty() || !all_expiring {
+                return InvalidTransaction::Stale.into();
+            }
+
+            ValidTransaction::with_tag_prefix("CertificationExpiry")
+                .priority(TransactionPriority::MAX / 2)
+                .and_provides(certification_ids)
+                .longevity(5)
+                .propagate(true)
+                .build()
+        }
+    }
+
+    /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+    /// These functions materialize as "extrinsics", which are often compared to transactions.
+    /// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+    /// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#dispatchables>
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Create a certification issued by and owned by the caller, reserving the deposit of
+        /// its `kind`, and emit [`Event::CertificationStored`].
+        ///
+        /// If `supersedes` is given, it must be a certification owned by the caller, which gets
+        /// linked to the new one through `superseded_by`.
+        ///
+        /// The expiry can be given either as an absolute `expires_at` block or as a `valid_for`
+        /// duration from the current block, but not both.
+        ///
+        /// A certification created with `transferable` set to false is soulbound: it can never
+        /// be offered or transferred to another account.
+        ///
+        /// A [`ContentType::Uri`] description must be ASCII and start with a scheme.
+        ///
+        /// An optional `external_id`, such as the DID of the holder, is indexed so the
+        /// certification can be found through [`Pallet::certifications_by_external_id`].
+        ///
+        /// The content is validated before any storage access, and a call failing that check
+        /// is only charged the base weight.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_certification(metadata.len() as u32))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn add_certification(
+            origin: OriginFor<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            content_type: ContentType,
+            kind: CertificationKind,
+            transferable: bool,
+            metadata: MetadataOf<T, I>,
+            expires_at: Option<BlockNumberFor<T>>,
+            valid_for: Option<BlockNumberFor<T>>,
+            supersedes: Option<CertificationIdOf<T>>,
+            request_id: Option<[u8; 32]>,
+            external_id: Option<ExternalIdOf<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            // Check that the extrinsic was signed and get the signer.
+            // This function will return an error if the extrinsic is not signed.
+            // <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_origin/index.html>
+            let who = ensure_signed(origin)?;
+            Self::ensure_valid_content(&title, &description, &content_type).map_err(|error| {
+                DispatchError::from(error).with_weight(Weight::from_parts(10_000, 0))
+            })?;
+            Self::ensure_not_paused()?;
+
+            if let Some(request_id) = request_id {
+                ensure!(
+                    !<SeenRequests<T, I>>::contains_key(&who, request_id),
+                    Error::<T, I>::DuplicateRequest
+                );
+            }
+
+            let expires_at = match (expires_at, valid_for) {
+                (Some(_), Some(_)) => return Err(Error::<T, I>::InvalidExpiry.into()),
+                (None, Some(valid_for)) => Some(
+                    frame_system::Pallet::<T>::block_number()
+                        .checked_add(&valid_for)
+                        .ok_or(Error::<T, I>::ExpiryOverflow)?,
+                ),
+                (expires_at, None) => expires_at,
+            };
+
+            Self::ensure_can_add(&who, &title, &description, &content_type, &kind)?;
+            Self::ensure_within_rate_limit(&who)?;
+            let certification_id = Self::do_add_certification(
+                who.clone(),
+                who.clone(),
+                title,
+                description,
+                content_type,
+                kind,
+                transferable,
+                metadata,
+                expires_at,
+                supersedes,
+            )?;
+            <LastCreatedAt<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
+            if let Some(request_id) = request_id {
+                <SeenRequests<T, I>>::insert(&who, request_id, certification_id);
+                <CertificationRequests<T, I>>::insert(certification_id, (who.clone(), request_id));
+            }
+            if let Some(external_id) = external_id {
+                <CertificationsByExternalId<T, I>>::insert(&external_id, certification_id, ());
+                <ExternalIds<T, I>>::insert(certification_id, external_id);
+            }
 
             // Return a successful [`DispatchResultWithPostInfo`] or [`DispatchResult`].
             Ok(().into())
         }
 
-        /// An example dispatchable that takes a singles value as a parameter, writes the value to
-        /// storage and emits an event. This function must be dispatched by a signed extrinsic.
+        /// Create one certification issued by and to the caller for each `(title, description)`
+        /// pair, each like [`Pallet::add_certification`] with default options, e.g. to import
+        /// existing records in one go.
+        ///
+        /// Fails with [`Error::GlobalCapReached`] if the batch as a whole does not fit under
+        /// [`Config::MaxTotalCertifications`], and otherwise with the error of the first
+        /// certification that cannot be created.
+        ///
+        /// All or nothing: if any certification cannot be created, the ones already created are
+        /// rolled back along with their index updates and nonce increments.
+        #[pallet::call_index(35)]
+        #[pallet::weight(Pallet::<T, I>::add_batch_weight(T::MaxBatchSize::get()))]
+        pub fn add_certifications(
+            origin: OriginFor<T>,
+            certifications: BoundedVec<(Vec<u8>, Vec<u8>), T::MaxBatchSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let count = certifications.len() as u32;
+            ensure!(
+                <CertificationCount<T, I>>::get().saturating_add(count)
+                    <= T::MaxTotalCertifications::get(),
+                Error::<T, I>::GlobalCapReached
+            );
+            Self::ensure_creation_cooldown_elapsed(&who)?;
+
+            with_storage_layer(|| -> DispatchResult {
+                for (title, description) in certifications {
+                    Self::ensure_within_rate_limit(&who)?;
+                    Self::do_add_certification(
+                        who.clone(),
+                        who.clone(),
+                        title,
+                        description,
+                        ContentType::default(),
+                        CertificationKind::default(),
+                        true,
+                        Default::default(),
+                        None,
+                        None,
+                    )?;
+                }
+                Ok(())
+            })?;
+            if count > 0 {
+                <LastCreatedAt<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
+            }
+
+            Ok(Some(Self::add_batch_weight(count)).into())
+        }
+
+        /// Replace the title and description of a certification, bumping its version, and emit
+        /// [`Event::CertificationUpdated`].
+        ///
+        /// `expected_version` must match the stored version of the certification, otherwise the
+        /// call fails with [`Error::VersionMismatch`] and the client should re-read and retry.
+        /// Clients tracking the last update block instead can pass `expected_updated_at`, which
+        /// fails the call with [`Error::StaleUpdate`] if it differs from the stored `updated_at`.
+        /// Updates within the same block are only told apart by the version.
+        ///
+        /// Must be called by the owner or one of the [`Editors`] of the certification. Frozen and
+        /// revoked certifications cannot be updated.
+        ///
+        /// Only the new title and description are checked against the current limits, so a
+        /// certification stored under higher limits can always be shrunk back within them.
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        #[pallet::weight(T::WeightInfo::update_certification())]
         pub fn update_certification(
             origin: OriginFor<T>,
-            certification_id: T::Hash,
+            certification_id: CertificationIdOf<T>,
             title: Vec<u8>,
             description: Vec<u8>,
+            expected_version: u32,
+            expected_updated_at: Option<BlockNumberFor<T>>,
         ) -> DispatchResultWithPostInfo {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_origin/index.html>
             let who = ensure_signed(origin)?;
-
-            let certification = <ListOfCertifications<T>>::get(certification_id)
-                .ok_or(Error::<T>::CertificationNotFound)?;
-
-            ensure!(certification.get_owner_id() == &who, Error::<T>::NotOwner);
+            Self::ensure_not_paused()?;
 
             // Convert the u32 into a block number. This is possible because the set of trait bounds
             // defined in [`frame_system::Config::BlockNumber`].
             let block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+            let (title, description) = Self::bound_content(title, description)?;
 
-            // Update storage.
-            <ListOfCertifications<T>>::insert(
+            // Update storage in place, bailing out before anything is written.
+            let owner = <ListOfCertifications<T, I>>::try_mutate(
+                &certification_id,
+                |maybe_certification| {
+                    let certification =
+                        Self::ensure_editable(maybe_certification, &certification_id, &who)?;
+                    ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+                    ensure!(!certification.revoked, Error::<T, I>::CertificationRevoked);
+                    ensure!(
+                        certification.version == expected_version,
+                        Error::<T, I>::VersionMismatch
+                    );
+                    if let Some(expected_updated_at) = expected_updated_at {
+                        ensure!(
+                            certification.updated_at == expected_updated_at,
+                            Error::<T, I>::StaleUpdate
+                        );
+                    }
+
+                    ensure!(
+                        certification.content_type.accepts(&description),
+                        Error::<T, I>::InvalidUri
+                    );
+                    let owner = certification.owner_id.clone();
+                    if certification.title != title {
+                        // Removed first, as both titles may share a sort key.
+                        Self::remove_from_title_index(
+                            &owner,
+                            &certification.title,
+                            &certification_id,
+                        );
+                        Self::insert_into_title_index(&owner, &title, certification_id)?;
+                    }
+                    certification.content_hash = Self::content_hash_of(&title, &description);
+                    certification.title = title;
+                    certification.description = description;
+                    certification.updated_at = block_number;
+                    certification.version = certification.version.wrapping_add(1);
+                    if T::ResetCreatedOnUpdate::get() {
+                        <CertificationsByBlock<T, I>>::remove(
+                            certification.created_at,
+                            certification_id,
+                        );
+                        <CertificationsByBlock<T, I>>::insert(block_number, certification_id, ());
+                        certification.created_at = block_number;
+                        certification.created_time =
+                            Some(T::Time::now()).filter(|now| !now.is_zero());
+                    }
+                    Ok::<_, Error<T, I>>(owner)
+                },
+            )?;
+            Self::record_history(certification_id, HistoryAction::Updated);
+
+            // Emit an event, indexed by the owner even when an editor made the update.
+            Self::deposit_indexed_event(
+                &owner,
                 certification_id,
-                Certification::new(
+                Event::CertificationUpdated {
+                    who: who.clone(),
                     certification_id,
-                    who.clone(),
-                    title,
-                    description,
-                    certification.created_at,
-                    block_number,
-                ),
+                    updated_at: block_number,
+                },
             );
 
-            // Emit an event.
-            Self::deposit_event(Event::CertificationUpdated {
-                who: who.clone(),
-                certification_id,
-                updated_at: block_number,
-            });
-
             // Return a successful [`DispatchResultWithPostInfo`] or [`DispatchResult`].
             Ok(().into())
         }
 
-        /// An example dispatchable that takes a singles value as a parameter, writes the value to
-        /// storage and emits an event. This function must be dispatched by a signed extrinsic.
+        /// Remove a non-frozen certification owned by the caller, along with its index entries,
+        /// metadata and editors, unreserving its deposit, and emit
+        /// [`Event::CertificationRemoved`].
+        ///
+        /// The optional `reason` is only recorded in the emitted event.
+        ///
+        /// Charged for a full owner index, and refunded down to the size of the owner's index.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        #[pallet::weight(Pallet::<T, I>::remove_weight(T::MaxCertificationsPerOwner::get()))]
         pub fn remove_certification(
             origin: OriginFor<T>,
-            certification_id: T::Hash,
+            certification_id: CertificationIdOf<T>,
+            reason: Option<ReasonOf<T, I>>,
         ) -> DispatchResultWithPostInfo {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/reference_docs/frame_origin/index.html>
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
 
-            let certification = <ListOfCertifications<T>>::get(certification_id)
-                .ok_or(Error::<T>::CertificationNotFound)?;
-
-            ensure!(certification.get_owner_id() == &who, Error::<T>::NotOwner);
+            let certification = Self::ensure_owner(&certification_id, &who)?;
+            ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+            let index_len = Self::count_of(&who);
 
             // Remove from storage.
-            <ListOfCertifications<T>>::remove(certification_id.clone());
+            Self::do_remove_certification(certification_id, certification);
 
             // Emit an event.
-            Self::deposit_event(Event::CertificationRemoved {
-                who: who.clone(),
+            Self::deposit_indexed_event(
+                &who,
                 certification_id,
-            });
+                Event::CertificationRemoved {
+                    who: who.clone(),
+                    certification_id,
+                    reason,
+                },
+            );
 
             // Return a successful [`DispatchResultWithPostInfo`] or [`DispatchResult`].
+            Ok(Some(Self::remove_weight(index_len)).into())
+        }
+
+        /// Remove any certification regardless of its owner, e.g. after a court order. Must be
+        /// dispatched by [`Config::ForceOrigin`].
+        ///
+        /// Unlike [`Pallet::remove_certification`], this also applies to frozen certifications.
+        /// The optional `reason` is only recorded in the emitted event.
+        ///
+        /// Charged like [`Pallet::remove_certification`] for a full owner index, and refunded
+        /// down to the size of the owner's index.
+        #[pallet::call_index(30)]
+        #[pallet::weight(Pallet::<T, I>::remove_weight(T::MaxCertificationsPerOwner::get()))]
+        pub fn force_remove_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            reason: Option<ReasonOf<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let certification = <ListOfCertifications<T, I>>::get(certification_id)
+                .ok_or(Error::<T, I>::CertificationNotFound)?;
+            let owner = certification.owner_id.clone();
+            let index_len = Self::count_of(&owner);
+            Self::do_remove_certification(certification_id, certification);
+
+            Self::deposit_event(Event::CertificationForceRemoved {
+                certification_id,
+                owner,
+                reason,
+            });
+
+            Ok(Some(Self::remove_weight(index_len)).into())
+        }
+
+        /// Set the schema version of a certification, e.g. after migrating its content to a new
+        /// format. Must be dispatched by [`Config::ForceOrigin`].
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn update_schema_version(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            schema_version: u16,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            <ListOfCertifications<T, I>>::try_mutate(certification_id, |maybe| {
+                let certification = maybe.as_mut().ok_or(Error::<T, I>::CertificationNotFound)?;
+                certification.schema_version = schema_version;
+                Ok::<_, Error<T, I>>(())
+            })?;
+
+            Self::deposit_event(Event::SchemaVersionUpdated {
+                certification_id,
+                schema_version,
+            });
+
+            Ok(().into())
+        }
+
+        /// Permanently delete up to `limit` archived certifications. Must be dispatched by
+        /// [`Config::ForceOrigin`].
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(1, 1).saturating_mul((*limit).into())
+        )]
+        pub fn purge_archive(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let removed = <ArchivedCertifications<T, I>>::clear(limit, None).backend;
+
+            Self::deposit_event(Event::ArchivePurged { removed });
+
+            Ok(().into())
+        }
+
+        /// Offer a certification to `to`, who becomes its owner once they call
+        /// [`Pallet::accept_transfer`]. Replaces any previous offer.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn offer_transfer(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            to: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let certification = Self::ensure_owner(&certification_id, &who)?;
+            ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+            ensure!(certification.transferable, Error::<T, I>::NotTransferable);
+            <PendingTransfers<T, I>>::insert(certification_id, &to);
+
+            Self::deposit_event(Event::TransferOffered {
+                certification_id,
+                from: who,
+                to,
+            });
+
+            Ok(().into())
+        }
+
+        /// Accept a transfer offered to the caller with [`Pallet::offer_transfer`].
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(4, 5)
+                + T::DbWeight::get().writes(T::MaxEditors::get().into())
+        )]
+        pub fn accept_transfer(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let recipient = <PendingTransfers<T, I>>::get(certification_id)
+                .ok_or(Error::<T, I>::NoPendingTransfer)?;
+            ensure!(recipient == who, Error::<T, I>::NotTransferRecipient);
+            let certification = <ListOfCertifications<T, I>>::get(certification_id)
+                .ok_or(Error::<T, I>::CertificationNotFound)?;
+            let from = certification.owner_id.clone();
+
+            Self::do_transfer(certification_id, certification, &who)?;
+
+            Self::deposit_event(Event::TransferAccepted {
+                certification_id,
+                from,
+                to: who,
+            });
+
+            Ok(().into())
+        }
+
+        /// Withdraw a pending transfer offer. Must be called by the owner.
+        #[pallet::call_index(7)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 1))]
+        pub fn cancel_transfer(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            Self::ensure_owner(&certification_id, &who)?;
+            <PendingTransfers<T, I>>::take(certification_id)
+                .ok_or(Error::<T, I>::NoPendingTransfer)?;
+
+            Self::deposit_event(Event::TransferCancelled { certification_id });
+
+            Ok(().into())
+        }
+
+        /// Set a metadata entry of a certification, overwriting the value if `key` already
+        /// exists. Must be called by the owner.
+        #[pallet::call_index(17)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_metadata(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            key: MetadataKeyOf<T, I>,
+            value: MetadataValueOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            Self::ensure_owner(&certification_id, &who)?;
+            if !<Metadata<T, I>>::contains_key(certification_id, &key) {
+                let entries = <Metadata<T, I>>::iter_key_prefix(certification_id).count() as u32;
+                ensure!(
+                    entries < T::MaxMetadataEntries::get(),
+                    Error::<T, I>::TooManyMetadataEntries
+                );
+            }
+            <Metadata<T, I>>::insert(certification_id, &key, value);
+
+            Self::deposit_event(Event::MetadataSet {
+                certification_id,
+                key,
+            });
+
+            Ok(().into())
+        }
+
+        /// Clear a metadata entry of a certification. Must be called by the owner.
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn clear_metadata(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            key: MetadataKeyOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            Self::ensure_owner(&certification_id, &who)?;
+            <Metadata<T, I>>::take(certification_id, &key)
+                .ok_or(Error::<T, I>::MetadataKeyNotFound)?;
+
+            Self::deposit_event(Event::MetadataCleared {
+                certification_id,
+                key,
+            });
+
+            Ok(().into())
+        }
+
+        /// Allow `editor` to update the content of a certification, through
+        /// [`Pallet::update_certification`], [`Pallet::set_content_type`] and
+        /// [`Pallet::append_description_chunk`]. Must be called by the owner.
+        #[pallet::call_index(28)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 1))]
+        pub fn grant_editor(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            editor: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            Self::ensure_owner(&certification_id, &who)?;
+            ensure!(editor != who, Error::<T, I>::OwnerIsEditor);
+            ensure!(
+                !<Editors<T, I>>::contains_key(certification_id, &editor),
+                Error::<T, I>::AlreadyEditor
+            );
+            let editors = <Editors<T, I>>::iter_key_prefix(certification_id).count() as u32;
+            ensure!(
+                editors < T::MaxEditors::get(),
+                Error::<T, I>::TooManyEditors
+            );
+            <Editors<T, I>>::insert(certification_id, &editor, ());
+
+            Self::deposit_event(Event::EditorGranted {
+                certification_id,
+                editor,
+            });
+
+            Ok(().into())
+        }
+
+        /// Withdraw the right of `editor` to edit a certification. Must be called by the owner.
+        #[pallet::call_index(29)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn revoke_editor(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            editor: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            Self::ensure_owner(&certification_id, &who)?;
+            <Editors<T, I>>::take(certification_id, &editor).ok_or(Error::<T, I>::NotEditor)?;
+
+            Self::deposit_event(Event::EditorRevoked {
+                certification_id,
+                editor,
+            });
+
             Ok(().into())
         }
+
+        /// Move every certification owned by the caller to `new_owner`, e.g. when migrating
+        /// accounts. Emits [`Event::AllCertificationsTransferred`] after the per-certification
+        /// [`Event::CertificationTransferred`] events.
+        ///
+        /// Fails with [`Error::TooManyToTransfer`] if the caller owns more than
+        /// [`Config::MaxTransferPerCall`] certifications, and with
+        /// [`Error::TooManyCertifications`] if `new_owner` cannot hold them all.
+        ///
+        /// All or nothing: if any certification cannot be moved, the ones already moved are
+        /// rolled back along with their index updates.
+        #[pallet::call_index(14)]
+        #[pallet::weight(Pallet::<T, I>::transfer_all_weight(T::MaxTransferPerCall::get()))]
+        pub fn transfer_all_certifications(
+            origin: OriginFor<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let ids = <CertificationsByOwner<T, I>>::get(&who);
+            ensure!(
+                ids.len() as u32 <= T::MaxTransferPerCall::get(),
+                Error::<T, I>::TooManyToTransfer
+            );
+
+            let count = ids.len() as u32;
+            with_storage_layer(|| -> DispatchResult {
+                for certification_id in ids {
+                    let certification = <ListOfCertifications<T, I>>::get(certification_id)
+                        .ok_or(Error::<T, I>::CertificationNotFound)?;
+                    Self::do_transfer(certification_id, certification, &new_owner)?;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AllCertificationsTransferred {
+                from: who,
+                to: new_owner,
+                count,
+            });
+
+            Ok(Some(Self::transfer_all_weight(count)).into())
+        }
+
+        /// Import a certification that `issuer` signed off-chain, owned by the caller.
+        ///
+        /// `signature` must be an encoded [`Config::OffchainSignature`] by `issuer` over
+        /// [`Pallet::claim_payload`], which binds the claimant so that the signature cannot be
+        /// replayed by another account. Each signature can only be redeemed once, see
+        /// [`ConsumedClaims`].
+        ///
+        /// Subject to the same creation cooldown and rate limit as [`Pallet::add_certification`].
+        #[pallet::call_index(15)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 6))]
+        pub fn claim_certification(
+            origin: OriginFor<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            issuer: T::AccountId,
+            signature: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let signature = T::OffchainSignature::decode(&mut &signature[..])
+                .map_err(|_| Error::<T, I>::InvalidSignature)?;
+            let payload = Self::claim_payload(&who, &title, &description, &issuer);
+            ensure!(
+                signature.verify(&payload[..], &issuer),
+                Error::<T, I>::InvalidSignature
+            );
+            let claim_hash = T::Hashing::hash(&payload);
+            ensure!(
+                !<ConsumedClaims<T, I>>::contains_key(claim_hash),
+                Error::<T, I>::ClaimAlreadyUsed
+            );
+
+            Self::ensure_creation_cooldown_elapsed(&who)?;
+            Self::ensure_within_rate_limit(&who)?;
+            Self::do_add_certification(
+                who.clone(),
+                issuer,
+                title,
+                description,
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+            )?;
+            <ConsumedClaims<T, I>>::insert(claim_hash, ());
+            <LastCreatedAt<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
+
+            Ok(().into())
+        }
+
+        /// Make the content of a certification readable by anyone (`public`), or only by its
+        /// owner.
+        #[pallet::call_index(16)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_visibility(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            public: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut certification = Self::ensure_owner(&certification_id, &who)?;
+            certification.public = public;
+            <ListOfCertifications<T, I>>::insert(certification_id, certification);
+
+            Self::deposit_event(Event::VisibilityChanged {
+                certification_id,
+                public,
+            });
+
+            Ok(().into())
+        }
+
+        /// Create a certification owned by `owner`, issued by the caller. The caller must be an
+        /// operator of `owner`, see [`Pallet::authorize_operator`].
+        ///
+        /// The creation counts against the rate limit of the operator and the creation cooldown
+        /// of `owner`, so operators cannot create for an owner faster than the owner could.
+        #[pallet::call_index(19)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(5, 5))]
+        pub fn add_certification_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let operator = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            ensure!(
+                <Operators<T, I>>::get(&owner).contains(&operator),
+                Error::<T, I>::NotAuthorizedOperator
+            );
+            Self::ensure_creation_cooldown_elapsed(&owner)?;
+            Self::ensure_within_rate_limit(&operator)?;
+            Self::do_add_certification(
+                owner.clone(),
+                operator,
+                title,
+                description,
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+            )?;
+            <LastCreatedAt<T, I>>::insert(&owner, frame_system::Pallet::<T>::block_number());
+
+            Ok(().into())
+        }
+
+        /// Allow `operator` to create certifications on behalf of the caller.
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn authorize_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            <Operators<T, I>>::try_mutate(&owner, |operators| {
+                ensure!(
+                    !operators.contains(&operator),
+                    Error::<T, I>::OperatorAlreadyAuthorized
+                );
+                operators
+                    .try_push(operator.clone())
+                    .map_err(|_| Error::<T, I>::TooManyOperators)
+            })?;
+
+            Self::deposit_event(Event::OperatorAuthorized { owner, operator });
+
+            Ok(().into())
+        }
+
+        /// Withdraw the authorization of `operator` to act on behalf of the caller.
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn revoke_operator(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            <Operators<T, I>>::try_mutate(&owner, |operators| {
+                let position = operators
+                    .iter()
+                    .position(|o| o == &operator)
+                    .ok_or(Error::<T, I>::NotAuthorizedOperator)?;
+                operators.remove(position);
+                Ok::<_, Error<T, I>>(())
+            })?;
+
+            Self::deposit_event(Event::OperatorRevoked { owner, operator });
+
+            Ok(().into())
+        }
+
+        /// Freeze a certification while it is under dispute, so that it cannot be updated,
+        /// transferred or removed. Must be dispatched by [`Config::ForceOrigin`].
+        #[pallet::call_index(11)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn freeze_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            Self::set_frozen(certification_id, true)?;
+            // A dispute freeze can no longer be lifted by the owner.
+            <EscrowFrozen<T, I>>::remove(certification_id);
+
+            Self::deposit_event(Event::CertificationFrozen { certification_id });
+
+            Ok(().into())
+        }
+
+        /// Lift the freeze of a certification. Must be dispatched by [`Config::ForceOrigin`], or
+        /// by the owner of a certification frozen by [`Pallet::transfer_and_freeze`].
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 2))]
+        pub fn unfreeze_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+                let who = ensure_signed(origin)?;
+                Self::ensure_not_paused()?;
+                ensure!(
+                    <EscrowFrozen<T, I>>::contains_key(certification_id),
+                    DispatchError::BadOrigin
+                );
+                Self::ensure_owner(&certification_id, &who)?;
+            }
+
+            Self::set_frozen(certification_id, false)?;
+            <EscrowFrozen<T, I>>::remove(certification_id);
+
+            Self::deposit_event(Event::CertificationUnfrozen { certification_id });
+
+            Ok(().into())
+        }
+
+        /// Transfer a certification owned by the caller to `new_owner` and freeze it in the same
+        /// call, e.g. to hand it over in escrow.
+        ///
+        /// The freeze can be lifted by `new_owner` or by [`Config::ForceOrigin`] through
+        /// [`Pallet::unfreeze_certification`].
+        #[pallet::call_index(27)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(4, 6)
+                + T::DbWeight::get().writes(T::MaxEditors::get().into())
+        )]
+        pub fn transfer_and_freeze(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let certification = Self::ensure_owner(&certification_id, &who)?;
+            Self::do_transfer(certification_id, certification, &new_owner)?;
+            Self::set_frozen(certification_id, true)?;
+            <EscrowFrozen<T, I>>::insert(certification_id, ());
+
+            Self::deposit_event(Event::CertificationFrozen { certification_id });
+
+            Ok(().into())
+        }
+
+        /// Give a certification to `new_owner` without the consent of its current owner, e.g.
+        /// to recover it after a lost key. Must be dispatched by [`Config::ForceOrigin`].
+        ///
+        /// Unlike a transfer, this also applies to frozen certifications. A certification frozen
+        /// by [`Pallet::transfer_and_freeze`] stays frozen, but the new owner cannot lift the
+        /// escrow freeze, only [`Config::ForceOrigin`] can. As with a transfer, the editors of the
+        /// certification lose their access.
+        #[pallet::call_index(21)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                + T::DbWeight::get().reads_writes(3, 5)
+                + T::DbWeight::get().writes(T::MaxEditors::get().into())
+        )]
+        pub fn reassign_owner(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let mut certification = <ListOfCertifications<T, I>>::get(certification_id)
+                .ok_or(Error::<T, I>::CertificationNotFound)?;
+            let old_owner = certification.owner_id.clone();
+            Self::remove_from_owner_index(&old_owner, &certification_id);
+            Self::insert_into_owner_index(&new_owner, certification_id)?;
+            let _ = <Editors<T, I>>::clear_prefix(certification_id, T::MaxEditors::get(), None);
+            // Removed first, as the new owner may be the old one.
+            Self::remove_from_title_index(&old_owner, &certification.title, &certification_id);
+            Self::insert_into_title_index(&new_owner, &certification.title, certification_id)?;
+
+            Self::note_self_issued(&certification, false);
+            certification.owner_id = new_owner.clone();
+            Self::note_self_issued(&certification, true);
+            certification.updated_at = frame_system::Pallet::<T>::block_number();
+            <ListOfCertifications<T, I>>::insert(certification_id, certification);
+            <PendingTransfers<T, I>>::remove(certification_id);
+            <EscrowFrozen<T, I>>::remove(certification_id);
+            Self::record_history(certification_id, HistoryAction::Transferred);
+
+            Self::deposit_event(Event::CertificationOwnerReassigned {
+                certification_id,
+                old_owner,
+                new_owner,
+            });
+
+            Ok(().into())
+        }
+
+        /// Replace a certification owned by the caller with a new one pointing back at it through
+        /// `supersedes`. The old certification is kept but marked as revoked.
+        #[pallet::call_index(20)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 7))]
+        pub fn reissue_certification(
+            origin: OriginFor<T>,
+            old_id: CertificationIdOf<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let old = Self::ensure_owner(&old_id, &who)?;
+            ensure!(!old.frozen, Error::<T, I>::CertificationFrozen);
+            ensure!(!old.revoked, Error::<T, I>::CertificationRevoked);
+
+            Self::ensure_within_rate_limit(&who)?;
+            Self::ensure_creation_cooldown_elapsed(&who)?;
+            let new_id = Self::do_add_certification(
+                who.clone(),
+                who.clone(),
+                title,
+                description,
+                old.content_type,
+                old.kind,
+                old.transferable,
+                Default::default(),
+                None,
+                Some(old_id),
+            )?;
+            let block_number = frame_system::Pallet::<T>::block_number();
+            <LastCreatedAt<T, I>>::insert(&who, block_number);
+
+            <ListOfCertifications<T, I>>::mutate(old_id, |old| {
+                if let Some(old) = old {
+                    old.revoked = true;
+                    old.updated_at = block_number;
+                }
+            });
+            <RevokedAt<T, I>>::insert(old_id, block_number);
+            Self::record_history(old_id, HistoryAction::Revoked);
+
+            Self::deposit_event(Event::CertificationReissued { old_id, new_id });
+
+            Ok(().into())
+        }
+
+        /// Revoke a certification, e.g. because it was issued in error. Must be called by the
+        /// issuer.
+        ///
+        /// The certification is kept, marked as revoked with the optional `reason`, until
+        /// `on_idle` prunes it after [`Config::TombstoneRetention`].
+        #[pallet::call_index(34)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 2))]
+        pub fn revoke_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            reason: Option<ReasonOf<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            <ListOfCertifications<T, I>>::try_mutate(&certification_id, |maybe_certification| {
+                let certification = maybe_certification
+                    .as_mut()
+                    .ok_or(Error::<T, I>::CertificationNotFound)?;
+                ensure!(certification.issuer == who, Error::<T, I>::NotIssuer);
+                ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+                ensure!(!certification.revoked, Error::<T, I>::CertificationRevoked);
+                certification.revoked = true;
+                certification.updated_at = block_number;
+                certification.revocation_reason = reason.clone();
+                Ok::<_, Error<T, I>>(())
+            })?;
+            <RevokedAt<T, I>>::insert(certification_id, block_number);
+            Self::record_history(certification_id, HistoryAction::Revoked);
+
+            Self::deposit_event(Event::CertificationRevoked {
+                who,
+                certification_id,
+                reason,
+            });
+
+            Ok(().into())
+        }
+
+        /// Check a batch of `(certification_id, expected_content_hash)` pairs in one call.
+        ///
+        /// Emits [`Event::BatchVerified`] with one result per item: `true` if the certification
+        /// exists and its content hash matches, `false` otherwise.
+        #[pallet::call_index(13)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads(items.len() as u64))
+        )]
+        pub fn verify_certifications_batch(
+            origin: OriginFor<T>,
+            items: BoundedVec<(CertificationIdOf<T>, T::Hash), T::MaxBatchSize>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let results = items
+                .iter()
+                .map(|(certification_id, expected_content_hash)| {
+                    <ListOfCertifications<T, I>>::get(certification_id).is_some_and(
+                        |certification| &certification.content_hash == expected_content_hash,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            Self::deposit_event(Event::BatchVerified {
+                results: BoundedVec::truncate_from(results),
+            });
+
+            Ok(().into())
+        }
+
+        /// Push back the expiry of a certification owned by the caller to `new_valid_until`,
+        /// leaving its content untouched.
+        ///
+        /// `new_valid_until` must be in the future and strictly later than the current expiry.
+        /// Frozen and revoked certifications cannot be renewed.
+        #[pallet::call_index(22)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn renew_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            new_valid_until: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            <ListOfCertifications<T, I>>::try_mutate(&certification_id, |maybe_certification| {
+                let certification = Self::ensure_owned(maybe_certification, &who)?;
+                ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+                ensure!(!certification.revoked, Error::<T, I>::CertificationRevoked);
+                let expires_at = certification
+                    .expires_at
+                    .ok_or(Error::<T, I>::NotRenewable)?;
+                ensure!(new_valid_until > block_number, Error::<T, I>::InvalidExpiry);
+                ensure!(
+                    new_valid_until > expires_at,
+                    Error::<T, I>::RenewalNotExtending
+                );
+                certification.expires_at = Some(new_valid_until);
+                certification.warned = false;
+                certification.updated_at = block_number;
+                Ok::<_, Error<T, I>>(())
+            })?;
+
+            Self::deposit_event(Event::CertificationRenewed {
+                certification_id,
+                new_valid_until,
+            });
+
+            Ok(().into())
+        }
+
+        /// Revive an expired certification owned by the caller, at most
+        /// [`Config::ReclaimGracePeriod`] blocks after its expiry.
+        ///
+        /// The certification is made valid again for as long as it originally was, counted from
+        /// the current block. Frozen and revoked certifications cannot be reclaimed, nor can one
+        /// whose validity period is empty because [`Config::ResetCreatedOnUpdate`] moved its
+        /// creation up to its expiry.
+        #[pallet::call_index(33)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn reclaim_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let new_valid_until = <ListOfCertifications<T, I>>::try_mutate(
+                &certification_id,
+                |maybe_certification| {
+                    let certification = Self::ensure_owned(maybe_certification, &who)?;
+                    ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+                    ensure!(!certification.revoked, Error::<T, I>::CertificationRevoked);
+                    let expires_at = certification
+                        .expires_at
+                        .ok_or(Error::<T, I>::NotRenewable)?;
+                    ensure!(block_number > expires_at, Error::<T, I>::NotExpired);
+                    ensure!(
+                        block_number <= expires_at.saturating_add(T::ReclaimGracePeriod::get()),
+                        Error::<T, I>::ReclaimWindowClosed
+                    );
+                    let validity = expires_at.saturating_sub(certification.created_at);
+                    ensure!(!validity.is_zero(), Error::<T, I>::NotRenewable);
+                    let new_valid_until = block_number.saturating_add(validity);
+                    certification.expires_at = Some(new_valid_until);
+                    certification.warned = false;
+                    certification.updated_at = block_number;
+                    Ok::<_, Error<T, I>>(new_valid_until)
+                },
+            )?;
+
+            Self::deposit_event(Event::CertificationReclaimed {
+                certification_id,
+                new_valid_until,
+            });
+
+            Ok(().into())
+        }
+
+        /// Report certifications expiring within [`Config::ExpiryWarningWindow`]. Submitted as
+        /// an unsigned transaction by the offchain worker. Certifications already warned about
+        /// are skipped.
+        #[pallet::call_index(10)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads(certification_ids.len() as u64))
+        )]
+        pub fn report_expiring(
+            origin: OriginFor<T>,
+            certification_ids: BoundedVec<CertificationIdOf<T>, T::MaxOffchainScan>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for certification_id in certification_ids {
+                Self::warn_expiring(&certification_id, now);
+            }
+
+            Ok(Pays::No.into())
+        }
+
+        /// Merge the near-duplicate `source_id` into `target_id`, both owned by the caller.
+        ///
+        /// Metadata entries of `source_id` whose key `target_id` lacks are copied over, and the
+        /// title and description of `source_id` are recorded in [`MergedFrom`], see
+        /// [`Pallet::merged_from`]. Then `source_id` is removed. Fails if the copied metadata
+        /// would exceed [`Config::MaxMetadataEntries`], or if [`Config::MaxMergedFrom`]
+        /// certifications were already merged into `target_id`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(
+                3 + 2 * T::MaxMetadataEntries::get() as u64 + T::MaxMergedFrom::get() as u64,
+                9 + 2 * T::MaxMetadataEntries::get() as u64,
+            ))
+        )]
+        pub fn merge_certifications(
+            origin: OriginFor<T>,
+            target_id: CertificationIdOf<T>,
+            source_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(target_id != source_id, Error::<T, I>::CannotMergeWithSelf);
+
+            let target = Self::ensure_owner(&target_id, &who)?;
+            let source = Self::ensure_owner(&source_id, &who)?;
+            ensure!(
+                !target.frozen && !source.frozen,
+                Error::<T, I>::CertificationFrozen
+            );
+
+            ensure!(
+                (<MergedFrom<T, I>>::iter_key_prefix(target_id).count() as u32)
+                    < T::MaxMergedFrom::get(),
+                Error::<T, I>::TooManyMerges
+            );
+            let mut entries = <Metadata<T, I>>::iter_key_prefix(target_id).count() as u32;
+            let missing: Vec<_> = <Metadata<T, I>>::iter_prefix(source_id)
+                .filter(|(key, _)| !<Metadata<T, I>>::contains_key(target_id, key))
+                .collect();
+            for (key, value) in missing {
+                ensure!(
+                    entries < T::MaxMetadataEntries::get(),
+                    Error::<T, I>::TooManyMetadataEntries
+                );
+                <Metadata<T, I>>::insert(target_id, key, value);
+                entries += 1;
+            }
+            <MergedFrom<T, I>>::insert(
+                target_id,
+                source_id,
+                (source.title.clone(), source.description.clone()),
+            );
+            Self::do_remove_certification(source_id, source);
+
+            Self::deposit_event(Event::CertificationsMerged {
+                target: target_id,
+                source: source_id,
+            });
+
+            Ok(().into())
+        }
+
+        /// Update the certification `certification_id` if it exists, or create it under exactly
+        /// that id otherwise, for clients that cannot tell which applies.
+        ///
+        /// An existing certification must be owned by the caller and is updated like
+        /// [`Pallet::update_certification`], without a version check, emitting
+        /// [`Event::CertificationUpdated`]. A new one is created like
+        /// [`Pallet::add_certification`] with default options, subject to the same limits,
+        /// emitting [`Event::CertificationStored`].
+        ///
+        /// A new certification can only take an id the caller derives itself, i.e.
+        /// [`Pallet::derive_id`] for the caller and the current [`CertificationNonce`], or
+        /// [`Pallet::content_id`] for the caller and the given content. Any other id fails with
+        /// [`Error::CertificationNotFound`], so that nobody can take the id another account's
+        /// next certification will get.
+        #[pallet::call_index(25)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(4, 8))]
+        pub fn upsert_certification(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            if <ListOfCertifications<T, I>>::contains_key(certification_id) {
+                let version = Self::ensure_owner(&certification_id, &who)?.version;
+                return Self::update_certification(
+                    frame_system::RawOrigin::Signed(who).into(),
+                    certification_id,
+                    title,
+                    description,
+                    version,
+                    None,
+                );
+            }
+
+            ensure!(
+                certification_id == Self::derive_id(&who, <CertificationNonce<T, I>>::get())
+                    || certification_id == Self::content_id(&who, &title, &description),
+                Error::<T, I>::CertificationNotFound
+            );
+            Self::ensure_within_rate_limit(&who)?;
+            Self::ensure_creation_cooldown_elapsed(&who)?;
+            Self::do_insert_certification(
+                certification_id,
+                who.clone(),
+                who.clone(),
+                title,
+                description,
+                ContentType::default(),
+                CertificationKind::default(),
+                true,
+                Default::default(),
+                None,
+                None,
+            )?;
+            <LastCreatedAt<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
+
+            Ok(().into())
+        }
+
+        /// Create a new certification issued by and to the caller, copying the content, kind,
+        /// transferability and metadata of `source_id`, which the caller must own.
+        ///
+        /// The copy gets a fresh id and timestamps and is neither revoked nor set to expire, so
+        /// it can serve as a template. It is subject to the same limits as
+        /// [`Pallet::add_certification`].
+        #[pallet::call_index(26)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(6, 8))]
+        pub fn clone_certification(
+            origin: OriginFor<T>,
+            source_id: CertificationIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let source = Self::ensure_owner(&source_id, &who)?;
+            Self::ensure_within_rate_limit(&who)?;
+            Self::ensure_creation_cooldown_elapsed(&who)?;
+            let new_id = Self::do_add_certification(
+                who.clone(),
+                who.clone(),
+                source.title.into_inner(),
+                source.description.into_inner(),
+                source.content_type,
+                source.kind,
+                source.transferable,
+                BoundedVec::truncate_from(Self::metadata_of(&source_id)),
+                None,
+                None,
+            )?;
+            <LastCreatedAt<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::CertificationCloned { source_id, new_id });
+
+            Ok(().into())
+        }
+
+        /// Change how the description of a certification should be rendered. Must be called by
+        /// the owner or an editor, and the current description must be valid for the new content type.
+        #[pallet::call_index(24)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_content_type(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            content_type: ContentType,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            <ListOfCertifications<T, I>>::try_mutate(&certification_id, |maybe_certification| {
+                let certification =
+                    Self::ensure_editable(maybe_certification, &certification_id, &who)?;
+                ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+                ensure!(
+                    content_type.accepts(&certification.description),
+                    Error::<T, I>::InvalidUri
+                );
+                certification.content_type = content_type;
+                Ok::<_, Error<T, I>>(())
+            })?;
+
+            Self::deposit_event(Event::ContentTypeSet {
+                certification_id,
+                content_type,
+            });
+
+            Ok(().into())
+        }
+
+        /// Write chunk `index` continuing the description of a certification. Must be called by
+        /// the owner or an editor.
+        ///
+        /// `index` is either the number of existing chunks, appending a new one, or the index of
+        /// an existing chunk, replacing it. At most [`Config::MaxChunks`] chunks are kept.
+        #[pallet::call_index(32)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1 + T::MaxChunks::get() as u64))
+                .saturating_add(T::DbWeight::get().writes(1))
+        )]
+        pub fn append_description_chunk(
+            origin: OriginFor<T>,
+            certification_id: CertificationIdOf<T>,
+            index: u16,
+            data: BoundedVec<u8, T::MaxChunkLen>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut certification = <ListOfCertifications<T, I>>::get(certification_id);
+            let certification = Self::ensure_editable(&mut certification, &certification_id, &who)?;
+            ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+            ensure!(
+                u32::from(index) < T::MaxChunks::get(),
+                Error::<T, I>::TooManyChunks
+            );
+            let chunks = <DescriptionChunks<T, I>>::iter_key_prefix(certification_id).count();
+            ensure!(usize::from(index) <= chunks, Error::<T, I>::ChunkIndexGap);
+            <DescriptionChunks<T, I>>::insert(certification_id, index, data);
+
+            Self::deposit_event(Event::DescriptionChunkAppended {
+                certification_id,
+                index,
+            });
+
+            Ok(().into())
+        }
+
+        /// Pause or unpause the pallet. While paused, every state-changing call fails with
+        /// [`Error::PalletPaused`], except those gated by [`Config::ForceOrigin`]. Queries keep
+        /// working.
+        #[pallet::call_index(31)]
+        #[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            <Paused<T, I>>::put(paused);
+            Self::deposit_event(Event::PalletPausedSet { paused });
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Fail with [`Error::PalletPaused`] while the pallet is paused.
+        pub(crate) fn ensure_not_paused() -> DispatchResult {
+            ensure!(!<Paused<T, I>>::get(), Error::<T, I>::PalletPaused);
+            Ok(())
+        }
+
+        /// Load a certification and check that it is owned by `who`. Used by every owner-gated
+        /// call, and available to other pallets gating on ownership.
+        ///
+        /// Fails with [`Error::CertificationNotFound`] or [`Error::NotOwner`], or with
+        /// [`Error::AccessDenied`] in both cases if [`Config::HideExistence`] is set.
+        pub fn ensure_owner(
+            certification_id: &CertificationIdOf<T>,
+            who: &T::AccountId,
+        ) -> Result<CertificationOf<T, I>, DispatchError> {
+            let mut certification = <ListOfCertifications<T, I>>::get(certification_id);
+            Ok(Self::ensure_owned(&mut certification, who).cloned()?)
+        }
+
+        /// Check that an already loaded certification exists and is owned by `who`, with the
+        /// same errors as [`Pallet::ensure_owner`]. Meant for `try_mutate` closures.
+        pub(crate) fn ensure_owned<'a>(
+            maybe_certification: &'a mut Option<CertificationOf<T, I>>,
+            who: &T::AccountId,
+        ) -> Result<&'a mut CertificationOf<T, I>, Error<T, I>> {
+            let hide = T::HideExistence::get();
+            let certification = maybe_certification.as_mut().ok_or(if hide {
+                Error::<T, I>::AccessDenied
+            } else {
+                Error::<T, I>::CertificationNotFound
+            })?;
+            if certification.get_owner_id() != who {
+                return Err(if hide {
+                    Error::<T, I>::AccessDenied
+                } else {
+                    Error::<T, I>::NotOwner
+                });
+            }
+            Ok(certification)
+        }
+
+        /// Like [`Pallet::ensure_owned`], but also lets through the [`Editors`] of the
+        /// certification, failing with [`Error::NotAuthorized`] for anyone else.
+        pub(crate) fn ensure_editable<'a>(
+            maybe_certification: &'a mut Option<CertificationOf<T, I>>,
+            certification_id: &CertificationIdOf<T>,
+            who: &T::AccountId,
+        ) -> Result<&'a mut CertificationOf<T, I>, Error<T, I>> {
+            let hide = T::HideExistence::get();
+            let certification = maybe_certification.as_mut().ok_or(if hide {
+                Error::<T, I>::AccessDenied
+            } else {
+                Error::<T, I>::CertificationNotFound
+            })?;
+            if certification.get_owner_id() != who
+                && !<Editors<T, I>>::contains_key(certification_id, who)
+            {
+                return Err(if hide {
+                    Error::<T, I>::AccessDenied
+                } else {
+                    Error::<T, I>::NotAuthorized
+                });
+            }
+            Ok(certification)
+        }
+
+        /// Ids of the certifications of `kind`.
+        pub fn certifications_of_kind(kind: CertificationKind) -> Vec<CertificationIdOf<T>> {
+            <CertificationsByKind<T, I>>::iter_key_prefix(kind).collect()
+        }
+
+        /// Ids of the certifications created in `block` that have not been removed.
+        pub fn certifications_created_at(block: BlockNumberFor<T>) -> Vec<CertificationIdOf<T>> {
+            <CertificationsByBlock<T, I>>::iter_key_prefix(block).collect()
+        }
+
+        /// Ids of the certifications created in blocks `from..=to` that have not been removed.
+        ///
+        /// At most [`MAX_CREATED_BETWEEN_BLOCKS`] blocks starting at `from` are scanned.
+        pub fn created_between(
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> Vec<CertificationIdOf<T>> {
+            let last = to.min(from.saturating_add((MAX_CREATED_BETWEEN_BLOCKS - 1).into()));
+            let mut ids = Vec::new();
+            let mut block = from;
+            while block <= last {
+                ids.extend(Self::certifications_created_at(block));
+                if block == last {
+                    break;
+                }
+                block.saturating_inc();
+            }
+            ids
+        }
+
+        /// The recorded changes of a certification, oldest first, across the pages of its
+        /// [`History`] that are kept.
+        pub fn history(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Vec<(BlockNumberFor<T>, HistoryAction)> {
+            let head = <HistoryHead<T, I>>::get(certification_id);
+            (head.saturating_sub(1)..=head)
+                .flat_map(|page| <History<T, I>>::get(certification_id, page))
+                .collect()
+        }
+
+        /// The id, title and description of each certification merged into `target_id`.
+        pub fn merged_from(
+            target_id: &CertificationIdOf<T>,
+        ) -> Vec<(CertificationIdOf<T>, TitleOf<T, I>, DescriptionOf<T, I>)> {
+            <MergedFrom<T, I>>::iter_prefix(target_id)
+                .map(|(source_id, (title, description))| (source_id, title, description))
+                .collect()
+        }
+
+        /// Metadata entries of a certification, sorted by key.
+        pub fn metadata_of(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Vec<(MetadataKeyOf<T, I>, MetadataValueOf<T, I>)> {
+            let mut entries: Vec<_> = <Metadata<T, I>>::iter_prefix(certification_id).collect();
+            entries.sort();
+            entries
+        }
+
+        /// Whether `certification` expires after `now` but within [`Config::ExpiryWarningWindow`].
+        pub fn is_expiring_soon(
+            certification: &CertificationOf<T, I>,
+            now: BlockNumberFor<T>,
+        ) -> bool {
+            certification.expires_at.is_some_and(|expires_at| {
+                expires_at > now && expires_at <= now.saturating_add(T::ExpiryWarningWindow::get())
+            })
+        }
+
+        /// Whether `certification` is expiring soon and no warning was emitted for it yet.
+        pub(crate) fn needs_expiry_warning(
+            certification: &CertificationOf<T, I>,
+            now: BlockNumberFor<T>,
+        ) -> bool {
+            !certification.warned && Self::is_expiring_soon(certification, now)
+        }
+
+        /// Emit [`Event::CertificationExpiringSoon`] for a certification if it still needs a
+        /// warning, and mark it as warned.
+        pub(crate) fn warn_expiring(
+            certification_id: &CertificationIdOf<T>,
+            now: BlockNumberFor<T>,
+        ) {
+            <ListOfCertifications<T, I>>::mutate(certification_id, |maybe_certification| {
+                let Some(certification) = maybe_certification else {
+                    return;
+                };
+                if let (true, Some(expires_at)) = (
+                    Self::needs_expiry_warning(certification, now),
+                    certification.expires_at,
+                ) {
+                    certification.warned = true;
+                    Self::deposit_event(Event::CertificationExpiringSoon {
+                        certification_id: *certification_id,
+                        expires_at,
+                    });
+                }
+            });
+        }
+
+        /// Offchain storage key under which the offchain worker indexes the ids of the
+        /// certifications created in `block`, for custom RPCs to read.
+        pub fn offchain_index_key(block: BlockNumberFor<T>) -> Vec<u8> {
+            let mut key = OCW_INDEX_PREFIX.to_vec();
+            block.encode_to(&mut key);
+            key
+        }
+
+        /// Write the ids of the certifications created in each block since the last indexed
+        /// one, up to `now`, to offchain storage under [`Pallet::offchain_index_key`].
+        ///
+        /// At most [`MAX_OCW_INDEXED_BLOCKS`] blocks are indexed per call. The last indexed block
+        /// is kept in offchain storage too, so indexing resumes where it stopped after a
+        /// restart. The very first call starts at `now`.
+        pub(crate) fn index_created_certifications(now: BlockNumberFor<T>) {
+            let indexed = StorageValueRef::persistent(OCW_INDEXED_BLOCK_KEY);
+            let mut block = match indexed.get::<BlockNumberFor<T>>() {
+                Ok(Some(last)) => last.saturating_add(1u32.into()),
+                _ => now,
+            };
+            if block > now {
+                return;
+            }
+            let last = now.min(block.saturating_add((MAX_OCW_INDEXED_BLOCKS - 1).into()));
+
+            while block <= last {
+                let ids = Self::certifications_created_at(block);
+                if !ids.is_empty() {
+                    StorageValueRef::persistent(&Self::offchain_index_key(block)).set(&ids);
+                }
+                block.saturating_inc();
+            }
+            indexed.set(&last);
+        }
+
+        /// Scan at most `limit` certifications, starting after the raw storage key `start` (or
+        /// from the beginning), for the ones that need an expiry warning.
+        ///
+        /// Also returns the id of the last scanned certification, or `None` once the end of
+        /// [`ListOfCertifications`] is reached, so the next scan starts over, and the number of
+        /// certifications scanned.
+        pub(crate) fn scan_expiring(
+            start: Option<Vec<u8>>,
+            now: BlockNumberFor<T>,
+            limit: u32,
+        ) -> (Vec<CertificationIdOf<T>>, Option<CertificationIdOf<T>>, u32) {
+            let iter = match start {
+                Some(start) => <ListOfCertifications<T, I>>::iter_from(start),
+                None => <ListOfCertifications<T, I>>::iter(),
+            };
+
+            let mut expiring = Vec::new();
+            let mut last = None;
+            let mut scanned = 0;
+            for (certification_id, certification) in iter.take(limit as usize) {
+                if Self::needs_expiry_warning(&certification, now) {
+                    expiring.push(certification_id);
+                }
+                last = Some(certification_id);
+                scanned += 1;
+            }
+            if scanned < limit {
+                last = None;
+            }
+            (expiring, last, scanned)
+        }
+
+        /// Ids of the certifications owned by `owner` whose title starts with `prefix`, in owner
+        /// index order, e.g. for autocompletion.
+        ///
+        /// Only the owner's index is scanned, so this reads at most
+        /// [`Config::MaxCertificationsPerOwner`] certifications.
+        pub fn certifications_with_title_prefix(
+            owner: &T::AccountId,
+            prefix: &[u8],
+        ) -> Vec<CertificationIdOf<T>> {
+            <CertificationsByOwner<T, I>>::get(owner)
+                .into_iter()
+                .filter(|certification_id| {
+                    <ListOfCertifications<T, I>>::get(certification_id)
+                        .is_some_and(|certification| certification.title.starts_with(prefix))
+                })
+                .collect()
+        }
+
+        /// The titles and ids of the certifications owned by `owner`, in alphabetical order of
+        /// title, then of id.
+        ///
+        /// [`TitlesByOwner`] already yields the entries ordered by their first
+        /// [`TITLE_SORT_KEY_LEN`] bytes, so only titles sharing that prefix are reordered.
+        pub fn titles_sorted(owner: &T::AccountId) -> Vec<(Vec<u8>, T::Hash)> {
+            let mut titles: Vec<_> = <TitlesByOwner<T, I>>::iter_key_prefix(owner)
+                .filter_map(|(_, certification_id)| {
+                    <ListOfCertifications<T, I>>::get(certification_id).map(|certification| {
+                        (
+                            certification.title.into_inner(),
+                            certification_id.into_inner(),
+                        )
+                    })
+                })
+                .collect();
+            titles.sort();
+            titles
+        }
+
+        /// Ids of the certifications owned by `owner`, oldest first, with certifications created
+        /// in the same block ordered by id.
+        ///
+        /// The order only depends on the certifications themselves, not on the order of the
+        /// owner index, so it does not change when certifications are transferred in or out.
+        pub fn certifications_of(owner: &T::AccountId) -> Vec<CertificationIdOf<T>> {
+            Self::owned_in_order(owner)
+                .into_iter()
+                .map(|(certification_id, _)| certification_id)
+                .collect()
+        }
+
+        /// The certifications owned by `owner`, in the order of [`Pallet::certifications_of`].
+        fn owned_in_order(
+            owner: &T::AccountId,
+        ) -> Vec<(CertificationIdOf<T>, CertificationOf<T, I>)> {
+            let mut certifications: Vec<_> = <CertificationsByOwner<T, I>>::get(owner)
+                .into_iter()
+                .filter_map(|certification_id| {
+                    <ListOfCertifications<T, I>>::get(certification_id)
+                        .map(|certification| (certification_id, certification))
+                })
+                .collect();
+            certifications.sort_by_key(|(certification_id, certification)| {
+                (certification.created_at, *certification_id)
+            });
+            certifications
+        }
+
+        /// The headers of the certifications owned by `owner`, in the order of
+        /// [`Pallet::certifications_of`]. The title of a private certification is left empty.
+        pub fn certification_headers_of(
+            owner: &T::AccountId,
+        ) -> Vec<CertificationHeader<T::AccountId, T::Hash>> {
+            Self::owned_in_order(owner)
+                .into_iter()
+                .map(|(_, certification)| CertificationHeader {
+                    id: certification.id.into_inner(),
+                    owner: certification.owner_id,
+                    title: if certification.public {
+                        certification.title.into_inner()
+                    } else {
+                        Vec::new()
+                    },
+                    created_at: certification.created_at.saturated_into(),
+                })
+                .collect()
+        }
+
+        /// The certifications of each of `owners`, as visible to anyone, in the order of
+        /// [`Pallet::certifications_of`].
+        ///
+        /// Only the first [`Config::MaxOwnersPerQuery`] owners are looked up. Every owner looked
+        /// up gets an entry, which is empty if they hold no certification.
+        pub fn certifications_of_many(
+            owners: Vec<T::AccountId>,
+        ) -> Vec<(T::AccountId, Vec<CertificationView<T::AccountId, T::Hash>>)> {
+            owners
+                .into_iter()
+                .take(T::MaxOwnersPerQuery::get() as usize)
+                .map(|owner| {
+                    let views = Self::certifications_of(&owner)
+                        .iter()
+                        .filter_map(|certification_id| Self::view_as(certification_id, None))
+                        .collect();
+                    (owner, views)
+                })
+                .collect()
+        }
+
+        /// The number of certifications owned by `owner`, and the ids of the `latest` most
+        /// recently created ones, newest first.
+        ///
+        /// This is the reverse of the order of [`Pallet::certifications_of`], so certifications
+        /// created in the same block come in decreasing id order.
+        pub fn summary_of(owner: &T::AccountId, latest: u32) -> (u32, Vec<CertificationIdOf<T>>) {
+            let count = <CertificationsByOwner<T, I>>::decode_len(owner).unwrap_or(0) as u32;
+            let newest = Self::owned_in_order(owner)
+                .into_iter()
+                .rev()
+                .take(latest as usize)
+                .map(|(certification_id, _)| certification_id)
+                .collect();
+            (count, newest)
+        }
+
+        /// Chain-wide certification statistics, read in one go for dashboards.
+        pub fn stats() -> CertificationStats {
+            CertificationStats {
+                total: <CertificationCount<T, I>>::get().into(),
+                nonce: <CertificationNonce<T, I>>::get().into(),
+            }
+        }
+
+        /// The configured limits, read in one go by clients validating input.
+        pub fn limits() -> CertificationLimits {
+            CertificationLimits {
+                max_certifications_per_owner: T::MaxCertificationsPerOwner::get(),
+                max_transfer_per_call: T::MaxTransferPerCall::get(),
+                max_creates_per_window: T::MaxCreatesPerWindow::get(),
+                max_total_certifications: T::MaxTotalCertifications::get(),
+                max_meta_key_len: T::MaxMetaKeyLen::get(),
+                max_meta_value_len: T::MaxMetaValueLen::get(),
+                max_metadata_entries: T::MaxMetadataEntries::get(),
+                max_operators: T::MaxOperators::get(),
+                max_offchain_scan: T::MaxOffchainScan::get(),
+                max_batch_size: T::MaxBatchSize::get(),
+                max_title_length: T::MaxTitleLength::get(),
+                min_title_length: T::MinTitleLength::get(),
+                max_description_length: T::MaxDescriptionLength::get(),
+                max_expiry_checks_per_block: T::MaxExpiryChecksPerBlock::get(),
+                max_reason_len: T::MaxReasonLen::get(),
+                max_chunk_len: T::MaxChunkLen::get(),
+                max_chunks: T::MaxChunks::get(),
+                max_owners_per_query: T::MaxOwnersPerQuery::get(),
+                max_external_id_len: T::MaxExternalIdLen::get(),
+                max_editors: T::MaxEditors::get(),
+            }
+        }
+
+        /// Check that the counters and owner indexes agree with [`ListOfCertifications`]:
+        ///
+        /// - [`CertificationCount`] equals the number of stored certifications;
+        /// - every certification decodes and is listed in its owner's [`CertificationsByOwner`];
+        /// - every [`CertificationsByOwner`] entry points to a certification owned by that
+        ///   account, and matches [`CertificationCountByOwner`], which has no other entries;
+        /// - [`Editors`], [`ExternalIds`], [`CertificationRequests`], [`History`] and
+        ///   [`MergedFrom`] only hold entries of stored certifications.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), TryRuntimeError> {
+            let mut total: u32 = 0;
+            for certification_id in <ListOfCertifications<T, I>>::iter_keys() {
+                total.saturating_inc();
+                let certification = <ListOfCertifications<T, I>>::try_get(certification_id)
+                    .map_err(|()| "certification does not decode")?;
+                ensure!(
+                    <CertificationsByOwner<T, I>>::get(&certification.owner_id)
+                        .contains(&certification_id),
+                    "certification missing from its owner's index"
+                );
+            }
+            ensure!(
+                <CertificationCount<T, I>>::get() == total,
+                "CertificationCount does not match the number of certifications"
+            );
+
+            for (owner, certification_ids) in <CertificationsByOwner<T, I>>::iter() {
+                ensure!(
+                    <CertificationCountByOwner<T, I>>::get(&owner)
+                        == certification_ids.len() as u32,
+                    "CertificationCountByOwner does not match the owner index"
+                );
+                for certification_id in certification_ids {
+                    ensure!(
+                        Self::owner_of(&certification_id).as_ref() == Some(&owner),
+                        "owner index points to a missing or foreign certification"
+                    );
+                }
+            }
+            for (owner, count) in <CertificationCountByOwner<T, I>>::iter() {
+                ensure!(
+                    <CertificationsByOwner<T, I>>::decode_len(&owner).unwrap_or(0) as u32 == count,
+                    "CertificationCountByOwner does not match the owner index"
+                );
+            }
+
+            let orphaned = <Editors<T, I>>::iter_keys()
+                .map(|(certification_id, _)| certification_id)
+                .chain(<ExternalIds<T, I>>::iter_keys())
+                .chain(<CertificationRequests<T, I>>::iter_keys())
+                .chain(<History<T, I>>::iter_keys().map(|(certification_id, _)| certification_id))
+                .chain(<HistoryHead<T, I>>::iter_keys())
+                .chain(
+                    <MergedFrom<T, I>>::iter_keys().map(|(certification_id, _)| certification_id),
+                )
+                .any(|certification_id| !Self::exists(&certification_id));
+            ensure!(
+                !orphaned,
+                "per-certification entry left behind by a removal"
+            );
+            Ok(())
+        }
+
+        /// All certifications that decode successfully.
+        ///
+        /// Entries that fail to decode, e.g. after an incomplete migration, are skipped with a
+        /// warning in the node log instead of aborting the iteration, see
+        /// [`Pallet::corrupt_entries`]. This is a read path and does not touch storage or emit
+        /// events, so it is safe to call from runtime APIs.
+        pub fn iter_valid() -> impl Iterator<Item = (CertificationIdOf<T>, CertificationOf<T, I>)> {
+            <ListOfCertifications<T, I>>::iter_keys().filter_map(|certification_id| {
+                match <ListOfCertifications<T, I>>::try_get(certification_id) {
+                    Ok(certification) => Some((certification_id, certification)),
+                    Err(()) => {
+                        log::warn!(
+                            target: "runtime::certification",
+                            "skipping undecodable certification {:?}",
+                            certification_id,
+                        );
+                        None
+                    }
+                }
+            })
+        }
+
+        /// Ids of the stored certifications that fail to decode, i.e. those skipped by
+        /// [`Pallet::iter_valid`]. Its length is the number of skipped entries.
+        pub fn corrupt_entries() -> Vec<CertificationIdOf<T>> {
+            <ListOfCertifications<T, I>>::iter_keys()
+                .filter(|certification_id| {
+                    <ListOfCertifications<T, I>>::try_get(certification_id).is_err()
+                })
+                .collect()
+        }
+
+        /// Whether a certification with the given id exists, without decoding it.
+        pub fn exists(certification_id: &CertificationIdOf<T>) -> bool {
+            <ListOfCertifications<T, I>>::contains_key(certification_id)
+        }
+
+        /// Ids of the certifications created with the external holder id `external_id`.
+        pub fn certifications_by_external_id(
+            external_id: &ExternalIdOf<T, I>,
+        ) -> Vec<CertificationIdOf<T>> {
+            <CertificationsByExternalId<T, I>>::iter_key_prefix(external_id).collect()
+        }
+
+        /// The full description of a certification: its description followed by its
+        /// [`DescriptionChunks`] in index order.
+        pub fn reassembled_description(certification_id: &CertificationIdOf<T>) -> Option<Vec<u8>> {
+            let mut description = <ListOfCertifications<T, I>>::get(certification_id)?
+                .description
+                .into_inner();
+            let mut chunks: Vec<_> =
+                <DescriptionChunks<T, I>>::iter_prefix(certification_id).collect();
+            chunks.sort_by_key(|(index, _)| *index);
+            for (_, chunk) in chunks {
+                description.extend(chunk);
+            }
+            Some(description)
+        }
+
+        /// The id of the certification most recently created for `who`, see [`LastCreated`].
+        pub fn last_created(who: &T::AccountId) -> Option<CertificationIdOf<T>> {
+            <LastCreated<T, I>>::get(who)
+        }
+
+        /// The owner of a certification, if it exists.
+        pub fn owner_of(certification_id: &CertificationIdOf<T>) -> Option<T::AccountId> {
+            <ListOfCertifications<T, I>>::get(certification_id).map(|c| c.owner_id)
+        }
+
+        /// The number of certifications owned by `owner`, without decoding their ids.
+        pub fn count_of(owner: &T::AccountId) -> u32 {
+            <CertificationCountByOwner<T, I>>::get(owner)
+        }
+
+        /// Whether `who` owns at least one certification, read from
+        /// [`CertificationCountByOwner`] without touching the owner index.
+        pub fn has_any_certification(who: &T::AccountId) -> bool {
+            Self::count_of(who) > 0
+        }
+
+        /// Whether `who` owns every certification in `ids`, stopping at the first one it does not.
+        /// Unknown ids count as not owned; an empty list is trivially owned.
+        pub fn owns_all(who: &T::AccountId, ids: &[CertificationIdOf<T>]) -> bool {
+            ids.iter()
+                .all(|id| Self::owner_of(id).as_ref() == Some(who))
+        }
+
+        /// Whether `who` owns at least one certification in `ids`, stopping at the first match.
+        pub fn owns_any(who: &T::AccountId, ids: &[CertificationIdOf<T>]) -> bool {
+            ids.iter()
+                .any(|id| Self::owner_of(id).as_ref() == Some(who))
+        }
+
+        /// A certification as visible to `caller`: the title and description of a private
+        /// certification are only revealed to its owner.
+        pub fn get_certification(
+            certification_id: &CertificationIdOf<T>,
+            caller: Option<&T::AccountId>,
+        ) -> Option<CertificationDetails<T::AccountId, T::Hash, BlockNumberFor<T>>> {
+            let certification = <ListOfCertifications<T, I>>::get(certification_id)?;
+            let readable = certification.public || caller == Some(&certification.owner_id);
+            Some(CertificationDetails {
+                id: certification.id,
+                owner_id: certification.owner_id,
+                issuer: certification.issuer,
+                title: readable.then_some(certification.title.into_inner()),
+                description: readable.then_some(certification.description.into_inner()),
+                public: certification.public,
+                created_at: certification.created_at,
+                updated_at: certification.updated_at,
+            })
+        }
+
+        /// A stored certification, failing with [`Error::CertificationNotFound`] if it does not
+        /// exist, so other pallets can use `?` instead of handling [`Option`] themselves.
+        ///
+        /// Fails with [`Error::CorruptedEntry`] if the entry exists but does not decode.
+        /// Unlike [`Pallet::get_certification`], private content is returned as stored.
+        pub fn get_certification_or_err(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Result<CertificationOf<T, I>, DispatchError> {
+            Self::try_get_certification(certification_id).map_err(|error| match error {
+                CertificationReadError::NotFound => Error::<T, I>::CertificationNotFound.into(),
+                CertificationReadError::DecodeFailed => Error::<T, I>::CorruptedEntry.into(),
+            })
+        }
+
+        /// A stored certification, read from raw storage and decoded by hand so that a missing
+        /// entry can be told apart from one that does not decode.
+        pub fn try_get_certification(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Result<CertificationOf<T, I>, CertificationReadError> {
+            let key = <ListOfCertifications<T, I>>::hashed_key_for(certification_id);
+            let raw = unhashed::get_raw(&key).ok_or(CertificationReadError::NotFound)?;
+            CertificationOf::<T, I>::decode(&mut &raw[..]).map_err(|_| {
+                log::warn!(
+                    target: "runtime::certification",
+                    "certification {:?} does not decode",
+                    certification_id,
+                );
+                CertificationReadError::DecodeFailed
+            })
+        }
+
+        /// A stored certification, or `None` if it is missing or does not decode into the
+        /// current [`Certification`] layout, e.g. during a staged migration.
+        pub fn certification(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Option<CertificationOf<T, I>> {
+            Self::get_certification_or_err(certification_id).ok()
+        }
+
+        /// A flat copy of a stored certification, for clients that should not depend on the
+        /// generic layout of [`Certification`].
+        pub fn view(
+            certification_id: &CertificationIdOf<T>,
+        ) -> Option<CertificationView<T::AccountId, T::Hash>> {
+            let certification = <ListOfCertifications<T, I>>::get(certification_id)?;
+            Some(CertificationView {
+                id: certification.id.into_inner(),
+                owner: certification.owner_id,
+                issuer: certification.issuer,
+                title: certification.title.into_inner(),
+                description: certification.description.into_inner(),
+                content_type: certification.content_type,
+                content_hash: certification.content_hash,
+                created_at: certification.created_at.saturated_into(),
+                updated_at: certification.updated_at.saturated_into(),
+                expires_at: certification.expires_at.map(|block| block.saturated_into()),
+                schema_version: certification.schema_version,
+                version: certification.version,
+                public: certification.public,
+                frozen: certification.frozen,
+                transferable: certification.transferable,
+                revoked: certification.revoked,
+            })
+        }
+
+        /// [`Pallet::view`] as visible to `caller`: the title and description of a private
+        /// certification are left empty unless `caller` is its owner.
+        pub fn view_as(
+            certification_id: &CertificationIdOf<T>,
+            caller: Option<&T::AccountId>,
+        ) -> Option<CertificationView<T::AccountId, T::Hash>> {
+            let mut view = Self::view(certification_id)?;
+            if !view.public && caller != Some(&view.owner) {
+                view.title.clear();
+                view.description.clear();
+            }
+            Some(view)
+        }
+
+        /// Wall-clock time at which a certification was created, if it exists and a time source
+        /// was available when it was created.
+        pub fn created_time(certification_id: &CertificationIdOf<T>) -> Option<MomentOf<T, I>> {
+            <ListOfCertifications<T, I>>::get(certification_id)?.created_time
+        }
+
+        /// Id of the certification created by `owner` when [`CertificationNonce`] is `nonce`,
+        /// unless [`Config::ContentAddressed`] is set.
+        ///
+        /// Together with [`Pallet::content_id`], this is the only place ids are derived, so
+        /// clients can predict the id of a certification before submitting it.
+        pub fn derive_id(owner: &T::AccountId, nonce: u32) -> CertificationIdOf<T> {
+            CertificationId(T::Hashing::hash_of(&(owner, nonce)))
+        }
+
+        /// Id of the certification created by `owner` with the given content when
+        /// [`Config::ContentAddressed`] is set.
+        pub fn content_id(
+            owner: &T::AccountId,
+            title: &[u8],
+            description: &[u8],
+        ) -> CertificationIdOf<T> {
+            CertificationId(T::Hashing::hash_of(&(owner, title, description)))
+        }
+
+        /// Id of the next certification created by `owner` with the given content, following
+        /// [`Config::ContentAddressed`].
+        pub(crate) fn new_id(
+            owner: &T::AccountId,
+            title: &[u8],
+            description: &[u8],
+        ) -> CertificationIdOf<T> {
+            if T::ContentAddressed::get() {
+                Self::content_id(owner, title, description)
+            } else {
+                Self::derive_id(owner, <CertificationNonce<T, I>>::get())
+            }
+        }
+
+        /// Check the title and description against [`Config::MinTitleLength`],
+        /// [`Config::MaxTitleLength`] and [`Config::MaxDescriptionLength`].
+        pub(crate) fn bound_content(
+            title: Vec<u8>,
+            description: Vec<u8>,
+        ) -> Result<(TitleOf<T, I>, DescriptionOf<T, I>), Error<T, I>> {
+            ensure!(
+                title.len() >= T::MinTitleLength::get() as usize,
+                Error::<T, I>::TitleTooShort
+            );
+            Ok((
+                title.try_into().map_err(|_| Error::<T, I>::TitleTooLong)?,
+                description
+                    .try_into()
+                    .map_err(|_| Error::<T, I>::DescriptionTooLong)?,
+            ))
+        }
+
+        /// Check the content of a new certification against the length limits and its content
+        /// type, like [`Pallet::bound_content`] but without touching storage or copying it.
+        pub(crate) fn ensure_valid_content(
+            title: &[u8],
+            description: &[u8],
+            content_type: &ContentType,
+        ) -> Result<(), Error<T, I>> {
+            ensure!(
+                title.len() >= T::MinTitleLength::get() as usize,
+                Error::<T, I>::TitleTooShort
+            );
+            ensure!(
+                title.len() <= T::MaxTitleLength::get() as usize,
+                Error::<T, I>::TitleTooLong
+            );
+            ensure!(
+                description.len() <= T::MaxDescriptionLength::get() as usize,
+                Error::<T, I>::DescriptionTooLong
+            );
+            ensure!(content_type.accepts(description), Error::<T, I>::InvalidUri);
+            Ok(())
+        }
+
+        /// Hash of a certification's content, computed with [`Config::ContentHasher`].
+        pub fn content_hash_of(title: &[u8], description: &[u8]) -> T::Hash {
+            T::ContentHasher::hash_of(&(title, description))
+        }
+
+        /// The message an issuer signs to let `holder` claim a certification with the given
+        /// content through [`Pallet::claim_certification`].
+        pub fn claim_payload(
+            holder: &T::AccountId,
+            title: &[u8],
+            description: &[u8],
+            issuer: &T::AccountId,
+        ) -> Vec<u8> {
+            (holder, title, description, issuer).encode()
+        }
+
+        /// Count a creation by `who` against its rate limiting window, failing with
+        /// [`Error::RateLimited`] once [`Config::MaxCreatesPerWindow`] is reached.
+        ///
+        /// A new window starts at the first creation made at least [`Config::WindowLength`]
+        /// blocks after the start of the previous one.
+        pub(crate) fn ensure_within_rate_limit(who: &T::AccountId) -> DispatchResult {
+            let window = Self::next_creation_window(who)?;
+            <CreationWindow<T, I>>::insert(who, window);
+            Ok(())
+        }
+
+        /// Fail with [`Error::CreationTooFrequent`] if `who` created a certification less than
+        /// [`Config::CreationCooldown`] blocks ago.
+        fn ensure_creation_cooldown_elapsed(who: &T::AccountId) -> Result<(), Error<T, I>> {
+            if let Some(last) = <LastCreatedAt<T, I>>::get(who) {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(
+                    now.saturating_sub(last) >= T::CreationCooldown::get(),
+                    Error::<T, I>::CreationTooFrequent
+                );
+            }
+            Ok(())
+        }
+
+        /// The rate limiting window of `who` after one more creation in the current block.
+        fn next_creation_window(
+            who: &T::AccountId,
+        ) -> Result<(BlockNumberFor<T>, u32), Error<T, I>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let (mut window_start, mut count) = <CreationWindow<T, I>>::get(who);
+            if count == 0 || now >= window_start.saturating_add(T::WindowLength::get()) {
+                window_start = now;
+                count = 0;
+            }
+            ensure!(
+                count < T::MaxCreatesPerWindow::get(),
+                Error::<T, I>::RateLimited
+            );
+            Ok((window_start, count + 1))
+        }
+
+        /// Run the checks of [`Pallet::add_certification`] for `who` without mutating state, and
+        /// return the id the certification would get if it were created in the current block.
+        pub fn preview_add(
+            who: &T::AccountId,
+            title: &[u8],
+            description: &[u8],
+            content_type: &ContentType,
+            kind: &CertificationKind,
+        ) -> Result<CertificationIdOf<T>, Error<T, I>> {
+            Self::ensure_can_add(who, title, description, content_type, kind)
+        }
+
+        /// The checks shared by [`Pallet::add_certification`] and [`Pallet::preview_add`]:
+        /// valid content, the pallet not paused, the rate limit and cooldown of `who`, a unique
+        /// title if [`Config::EnforceUniqueTitlePerOwner`] is set, a free id, the global and
+        /// per-owner caps, and a deposit `who` can reserve.
+        ///
+        /// Reads storage only, and returns the id the certification would get.
+        pub(crate) fn ensure_can_add(
+            who: &T::AccountId,
+            title: &[u8],
+            description: &[u8],
+            content_type: &ContentType,
+            kind: &CertificationKind,
+        ) -> Result<CertificationIdOf<T>, Error<T, I>> {
+            Self::ensure_valid_content(title, description, content_type)?;
+            ensure!(!<Paused<T, I>>::get(), Error::<T, I>::PalletPaused);
+            Self::next_creation_window(who)?;
+            Self::ensure_creation_cooldown_elapsed(who)?;
+            ensure!(
+                !T::EnforceUniqueTitlePerOwner::get()
+                    || !<TitleIndex<T, I>>::contains_key(who, T::Hashing::hash(title)),
+                Error::<T, I>::DuplicateTitleForOwner
+            );
+
+            let certification_id = Self::new_id(who, title, description);
+            ensure!(
+                !T::ContentAddressed::get()
+                    || !<ListOfCertifications<T, I>>::contains_key(certification_id),
+                Error::<T, I>::CertificationAlreadyExists
+            );
+            ensure!(
+                !<ListOfCertifications<T, I>>::contains_key(certification_id),
+                Error::<T, I>::IdCollision
+            );
+            ensure!(
+                <CertificationCount<T, I>>::get() < T::MaxTotalCertifications::get(),
+                Error::<T, I>::GlobalCapReached
+            );
+            let owned = <CertificationsByOwner<T, I>>::decode_len(who).unwrap_or(0) as u32;
+            ensure!(
+                owned < T::MaxCertificationsPerOwner::get(),
+                Error::<T, I>::TooManyCertifications
+            );
+            let deposit = T::CertificationDeposit::deposit_for(kind);
+            ensure!(
+                deposit.is_zero() || T::Currency::can_reserve(who, deposit),
+                Error::<T, I>::InsufficientDeposit
+            );
+            Ok(certification_id)
+        }
+
+        /// Set the `frozen` flag of a certification.
+        fn set_frozen(certification_id: CertificationIdOf<T>, frozen: bool) -> DispatchResult {
+            <ListOfCertifications<T, I>>::try_mutate(certification_id, |maybe| {
+                let certification = maybe.as_mut().ok_or(Error::<T, I>::CertificationNotFound)?;
+                certification.frozen = frozen;
+                Ok(())
+            })
+        }
+
+        /// Index into [`CertificationsByKind`] the certifications following [`KindIndexCursor`],
+        /// as many as `meter` allows, and clear the cursor once all are indexed. Does nothing
+        /// unless a rebuild is pending.
+        ///
+        /// Entries that do not decode, e.g. because they predate [`Certification::kind`], are
+        /// indexed as [`CertificationKind::Other`].
+        pub(crate) fn rebuild_kind_index(meter: &mut WeightMeter) {
+            let Some(cursor) = <KindIndexCursor<T, I>>::get() else {
+                return;
+            };
+            // Reserve the final cursor update, then pay for each certification indexed.
+            if meter
+                .try_consume(T::DbWeight::get().reads_writes(1, 1))
+                .is_err()
+            {
+                return;
+            }
+            let step = T::DbWeight::get().reads_writes(1, 1);
+            let mut keys =
+                match cursor {
+                    Some(last) => <ListOfCertifications<T, I>>::iter_keys_from(
+                        <ListOfCertifications<T, I>>::hashed_key_for(last),
+                    ),
+                    None => <ListOfCertifications<T, I>>::iter_keys(),
+                };
+            let mut last = cursor;
+            while meter.try_consume(step).is_ok() {
+                let Some(certification_id) = keys.next() else {
+                    <KindIndexCursor<T, I>>::kill();
+                    return;
+                };
+                let kind = <ListOfCertifications<T, I>>::try_get(certification_id)
+                    .map(|certification| certification.kind)
+                    .unwrap_or_default();
+                <CertificationsByKind<T, I>>::insert(kind, certification_id, ());
+                last = Some(certification_id);
+            }
+            <KindIndexCursor<T, I>>::put(last);
+        }
+
+        /// Record that `owner` holds the certification `certification_id` titled `title` in
+        /// [`TitlesByOwner`], failing if [`Config::EnforceUniqueTitlePerOwner`] is set and the
+        /// owner already holds that title.
+        pub(crate) fn insert_into_title_index(
+            owner: &T::AccountId,
+            title: &[u8],
+            certification_id: CertificationIdOf<T>,
+        ) -> Result<(), Error<T, I>> {
+            if T::EnforceUniqueTitlePerOwner::get() {
+                let title_hash = T::Hashing::hash(title);
+                ensure!(
+                    !<TitleIndex<T, I>>::contains_key(owner, title_hash),
+                    Error::<T, I>::DuplicateTitleForOwner
+                );
+                <TitleIndex<T, I>>::insert(owner, title_hash, ());
+            }
+            <TitlesByOwner<T, I>>::insert(
+                owner,
+                (Self::title_sort_key(title), certification_id),
+                (),
+            );
+            Ok(())
+        }
+
+        /// Undo [`Pallet::insert_into_title_index`].
+        pub(crate) fn remove_from_title_index(
+            owner: &T::AccountId,
+            title: &[u8],
+            certification_id: &CertificationIdOf<T>,
+        ) {
+            if T::EnforceUniqueTitlePerOwner::get() {
+                <TitleIndex<T, I>>::remove(owner, T::Hashing::hash(title));
+            }
+            <TitlesByOwner<T, I>>::remove(owner, (Self::title_sort_key(title), *certification_id));
+        }
+
+        /// The [`TitlesByOwner`] sort key of `title`: its first [`TITLE_SORT_KEY_LEN`] bytes,
+        /// padded with zeros so that shorter titles sort first.
+        pub(crate) fn title_sort_key(title: &[u8]) -> [u8; TITLE_SORT_KEY_LEN] {
+            let mut key = [0u8; TITLE_SORT_KEY_LEN];
+            let len = title.len().min(TITLE_SORT_KEY_LEN);
+            key[..len].copy_from_slice(&title[..len]);
+            key
+        }
+
+        /// Add a certification to the [`CertificationsByOwner`] entry of `owner`, at its sorted
+        /// position if [`Config::SortOwnerIndex`] is set and at the end otherwise.
+        pub(crate) fn insert_into_owner_index(
+            owner: &T::AccountId,
+            certification_id: CertificationIdOf<T>,
+        ) -> Result<(), Error<T, I>> {
+            <CertificationsByOwner<T, I>>::try_mutate(owner, |ids| {
+                if T::SortOwnerIndex::get() {
+                    let position = ids.binary_search(&certification_id).unwrap_or_else(|p| p);
+                    ids.try_insert(position, certification_id)
+                } else {
+                    ids.try_push(certification_id)
+                }
+            })
+            .map_err(|_| Error::<T, I>::TooManyCertifications)?;
+            <CertificationCountByOwner<T, I>>::mutate(owner, |count| *count += 1);
+            if T::EmitIndexEvents::get() {
+                Self::deposit_event(Event::OwnerIndexAdded {
+                    owner: owner.clone(),
+                    certification_id,
+                });
+            }
+            Ok(())
+        }
+
+        /// Remove a certification from the [`CertificationsByOwner`] entry of `owner`.
+        pub(crate) fn remove_from_owner_index(
+            owner: &T::AccountId,
+            certification_id: &CertificationIdOf<T>,
+        ) {
+            let removed = <CertificationsByOwner<T, I>>::mutate(owner, |ids| {
+                let len = ids.len();
+                if T::SortOwnerIndex::get() {
+                    if let Ok(position) = ids.binary_search(certification_id) {
+                        ids.remove(position);
+                    }
+                } else {
+                    ids.retain(|id| id != certification_id);
+                }
+                ids.len() < len
+            });
+            if removed {
+                <CertificationCountByOwner<T, I>>::mutate_exists(owner, |count| {
+                    *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+                });
+                if T::EmitIndexEvents::get() {
+                    Self::deposit_event(Event::OwnerIndexRemoved {
+                        owner: owner.clone(),
+                        certification_id: *certification_id,
+                    });
+                }
+            }
+        }
+
+        /// Count `certification` in, or out of, the [`SelfIssuedCount`] of its owner if it is
+        /// self-issued.
+        pub(crate) fn note_self_issued(certification: &CertificationOf<T, I>, added: bool) {
+            if !certification.is_self_issued() {
+                return;
+            }
+            <SelfIssuedCount<T, I>>::mutate_exists(&certification.owner_id, |count| {
+                let current = count.unwrap_or_default();
+                let updated = if added {
+                    current.saturating_add(1)
+                } else {
+                    current.saturating_sub(1)
+                };
+                *count = Some(updated).filter(|c| *c > 0);
+            });
+        }
+
+        /// Deposit `event` with the hash of `owner` and the certification id as topics, so that
+        /// indexers can subscribe to the events of a single owner or certification.
+        pub(crate) fn deposit_indexed_event(
+            owner: &T::AccountId,
+            certification_id: CertificationIdOf<T>,
+            event: Event<T, I>,
+        ) {
+            let topics = [T::Hashing::hash_of(owner), certification_id.into_inner()];
+            let event = <T as Config<I>>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
+
+        /// Append `action` at the current block to the [`History`] of a certification, starting
+        /// a new page once the current one is full and dropping the page before it.
+        pub(crate) fn record_history(
+            certification_id: CertificationIdOf<T>,
+            action: HistoryAction,
+        ) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut page = <HistoryHead<T, I>>::get(certification_id);
+            let mut entries = <History<T, I>>::get(certification_id, page);
+            if entries.is_full() {
+                page.saturating_inc();
+                entries = Default::default();
+                <HistoryHead<T, I>>::insert(certification_id, page);
+                if let Some(oldest) = page.checked_sub(2) {
+                    <History<T, I>>::remove(certification_id, oldest);
+                }
+            }
+            if entries.try_push((now, action)).is_err() {
+                // Only possible with pages of zero entries, i.e. history disabled.
+                return;
+            }
+            <History<T, I>>::insert(certification_id, page, entries);
+        }
+
+        /// Upper bound of [`Pallet::do_remove_certification`] when the owner holds `n`
+        /// certifications.
+        ///
+        /// [`WeightInfo::remove_certification`] is benchmarked on a certification without
+        /// metadata, description chunks, editors or merge records, so clearing each of those up
+        /// to its bound is added, along with archiving the certification, unreserving its
+        /// deposit, clearing its request id and clearing its [`History`].
+        pub(crate) fn remove_weight(n: u32) -> Weight {
+            let cleared = T::MaxMetadataEntries::get()
+                .saturating_add(T::MaxChunks::get())
+                .saturating_add(T::MaxEditors::get())
+                .saturating_add(T::MaxMergedFrom::get());
+            T::WeightInfo::remove_certification(n)
+                .saturating_add(T::DbWeight::get().writes(cleared.into()))
+                .saturating_add(T::DbWeight::get().reads_writes(3, 8))
+        }
+
+        /// Upper bound of [`Pallet::add_certifications`] for a batch of `n`, each certification
+        /// charged like an [`Pallet::add_certification`] without metadata.
+        pub(crate) fn add_batch_weight(n: u32) -> Weight {
+            T::WeightInfo::add_certification(0)
+                .saturating_mul(n.into())
+                .saturating_add(Weight::from_parts(10_000, 0))
+        }
+
+        /// Upper bound of [`Pallet::transfer_all_certifications`] moving `n` certifications.
+        ///
+        /// [`WeightInfo::transfer_all_certifications`] is benchmarked on certifications without
+        /// editors, so clearing up to [`Config::MaxEditors`] of each is added.
+        pub(crate) fn transfer_all_weight(n: u32) -> Weight {
+            T::WeightInfo::transfer_all_certifications(n).saturating_add(
+                T::DbWeight::get()
+                    .writes(T::MaxEditors::get().into())
+                    .saturating_mul(n.into()),
+            )
+        }
+
+        /// Make `to` the owner of a certification, updating the owner index and dropping any
+        /// pending transfer offer. Editors granted by the previous owner lose their access.
+        pub(crate) fn do_transfer(
+            certification_id: CertificationIdOf<T>,
+            mut certification: CertificationOf<T, I>,
+            to: &T::AccountId,
+        ) -> DispatchResult {
+            ensure!(!certification.frozen, Error::<T, I>::CertificationFrozen);
+            ensure!(certification.transferable, Error::<T, I>::NotTransferable);
+            let from = certification.owner_id.clone();
+            Self::remove_from_owner_index(&from, &certification_id);
+            Self::insert_into_owner_index(to, certification_id)?;
+            let _ = <Editors<T, I>>::clear_prefix(certification_id, T::MaxEditors::get(), None);
+            // Removed first, as `to` may be `from`.
+            Self::remove_from_title_index(&from, &certification.title, &certification_id);
+            Self::insert_into_title_index(to, &certification.title, certification_id)?;
+
+            Self::note_self_issued(&certification, false);
+            certification.owner_id = to.clone();
+            Self::note_self_issued(&certification, true);
+            certification.updated_at = frame_system::Pallet::<T>::block_number();
+            <ListOfCertifications<T, I>>::insert(certification_id, certification);
+            <PendingTransfers<T, I>>::remove(certification_id);
+            Self::record_history(certification_id, HistoryAction::Transferred);
+
+            Self::deposit_event(Event::CertificationTransferred {
+                from,
+                to: to.clone(),
+                certification_id,
+            });
+
+            Ok(())
+        }
+
+        /// Delete a certification and its index entries, archiving it if
+        /// [`Config::ArchiveRemoved`] is set.
+        pub(crate) fn do_remove_certification(
+            certification_id: CertificationIdOf<T>,
+            certification: CertificationOf<T, I>,
+        ) {
+            <ListOfCertifications<T, I>>::remove(certification_id);
+            Self::remove_from_owner_index(&certification.owner_id, &certification_id);
+            Self::note_self_issued(&certification, false);
+            Self::remove_from_title_index(
+                &certification.owner_id,
+                &certification.title,
+                &certification_id,
+            );
+            <CertificationsByBlock<T, I>>::remove(certification.created_at, certification_id);
+            <CertificationsByKind<T, I>>::remove(certification.kind, certification_id);
+            <PendingTransfers<T, I>>::remove(certification_id);
+            <EscrowFrozen<T, I>>::remove(certification_id);
+            let _ = <History<T, I>>::clear_prefix(certification_id, 2, None);
+            <HistoryHead<T, I>>::remove(certification_id);
+            if let Some(external_id) = <ExternalIds<T, I>>::take(certification_id) {
+                <CertificationsByExternalId<T, I>>::remove(external_id, certification_id);
+            }
+            if let Some((who, request_id)) = <CertificationRequests<T, I>>::take(certification_id) {
+                <SeenRequests<T, I>>::remove(who, request_id);
+            }
+            <RevokedAt<T, I>>::remove(certification_id);
+            let _ = <Metadata<T, I>>::clear_prefix(
+                certification_id,
+                T::MaxMetadataEntries::get(),
+                None,
+            );
+            let _ = <DescriptionChunks<T, I>>::clear_prefix(
+                certification_id,
+                T::MaxChunks::get(),
+                None,
+            );
+            let _ = <Editors<T, I>>::clear_prefix(certification_id, T::MaxEditors::get(), None);
+            let _ =
+                <MergedFrom<T, I>>::clear_prefix(certification_id, T::MaxMergedFrom::get(), None);
+            if let Some((depositor, deposit)) =
+                <CertificationDeposits<T, I>>::take(certification_id)
+            {
+                T::Currency::unreserve(&depositor, deposit);
+            }
+            <CertificationCount<T, I>>::mutate(|count| *count = count.saturating_sub(1));
+
+            if T::ArchiveRemoved::get() {
+                <ArchivedCertifications<T, I>>::insert(
+                    certification_id,
+                    ArchivedCertification {
+                        certification,
+                        removed_at: frame_system::Pallet::<T>::block_number(),
+                    },
+                );
+            }
+        }
+
+        /// Create a new certification owned by `who` and issued by `issuer`, and return its id.
+        ///
+        /// The id follows [`Config::ContentAddressed`], see [`Pallet::derive_id`]. Shared by
+        /// [`Pallet::add_certification`] and [`CertificationIssuer::issue`].
+        ///
+        /// Extrinsics cannot return values, so callers of the dispatchable read the id from
+        /// [`Event::CertificationStored`]. Other pallets can call this directly to get the id
+        /// back. Unlike the dispatchable, this does not apply the per-account rate limit or
+        /// creation cooldown.
+        #[allow(clippy::too_many_arguments)]
+        pub fn do_add_certification(
+            who: T::AccountId,
+            issuer: T::AccountId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            content_type: ContentType,
+            kind: CertificationKind,
+            transferable: bool,
+            metadata: MetadataOf<T, I>,
+            expires_at: Option<BlockNumberFor<T>>,
+            supersedes: Option<CertificationIdOf<T>>,
+        ) -> Result<CertificationIdOf<T>, DispatchError> {
+            let certification_id = Self::new_id(&who, &title, &description);
+            ensure!(
+                !T::ContentAddressed::get()
+                    || !<ListOfCertifications<T, I>>::contains_key(certification_id),
+                Error::<T, I>::CertificationAlreadyExists
+            );
+            Self::do_insert_certification(
+                certification_id,
+                who,
+                issuer,
+                title,
+                description,
+                content_type,
+                kind,
+                transferable,
+                metadata,
+                expires_at,
+                supersedes,
+            )
+        }
+
+        /// Create a new certification under `certification_id`, owned by `who` and issued by
+        /// `issuer`. Fails with [`Error::IdCollision`] if the id is taken.
+        ///
+        /// Bumps [`CertificationNonce`] and reserves [`Config::CertificationDeposit`] for `kind`
+        /// from `who`.
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn do_insert_certification(
+            certification_id: CertificationIdOf<T>,
+            who: T::AccountId,
+            issuer: T::AccountId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            content_type: ContentType,
+            kind: CertificationKind,
+            transferable: bool,
+            metadata: MetadataOf<T, I>,
+            expires_at: Option<BlockNumberFor<T>>,
+            supersedes: Option<CertificationIdOf<T>>,
+        ) -> Result<CertificationIdOf<T>, DispatchError> {
+            // Convert the u32 into a block number. This is possible because the set of trait bounds
+            // defined in [`frame_system::Config::BlockNumber`].
+            let block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > block_number, Error::<T, I>::InvalidExpiry);
+            }
+            let (title, description) = Self::bound_content(title, description)?;
+            ensure!(
+                content_type.accepts(&description),
+                Error::<T, I>::InvalidUri
+            );
+            if let Some(old) = supersedes {
+                ensure!(
+                    Self::owner_of(&old).as_ref() == Some(&who),
+                    Error::<T, I>::InvalidSupersedeTarget
+                );
+            }
+
+            let schema_version = T::CurrentSchemaVersion::get();
+            let nonce = <CertificationNonce<T, I>>::get();
+            // Never overwrite an existing entry, even if the derivation somehow repeats an id.
+            // This is an invariant violation rather than a user error, so make it loud.
+            if <ListOfCertifications<T, I>>::contains_key(certification_id) {
+                log::error!(
+                    target: "runtime::certification",
+                    "certification id {:?} is already taken at nonce {}",
+                    certification_id,
+                    nonce,
+                );
+                return Err(Error::<T, I>::IdCollision.into());
+            }
+
+            let count = <CertificationCount<T, I>>::get();
+            ensure!(
+                count < T::MaxTotalCertifications::get(),
+                Error::<T, I>::GlobalCapReached
+            );
+
+            let deposit = T::CertificationDeposit::deposit_for(&kind);
+            if !deposit.is_zero() {
+                T::Currency::reserve(&who, deposit)?;
+                <CertificationDeposits<T, I>>::insert(certification_id, (who.clone(), deposit));
+            }
+
+            // Update storage.
+            Self::insert_into_owner_index(&who, certification_id)?;
+            Self::insert_into_title_index(&who, &title, certification_id)?;
+            <CertificationsByBlock<T, I>>::insert(block_number, certification_id, ());
+            <CertificationsByKind<T, I>>::insert(kind, certification_id, ());
+            Self::record_history(certification_id, HistoryAction::Created);
+            let content_hash = Self::content_hash_of(&title, &description);
+            let (event_title, event_description) = if T::EmitContentInEvents::get() {
+                (Some(title.to_vec()), Some(description.to_vec()))
+            } else {
+                (None, None)
+            };
+            let certification = Certification::new(
+                certification_id,
+                who.clone(),
+                issuer.clone(),
+                title,
+                description,
+                content_hash,
+                block_number,
+                Some(T::Time::now()).filter(|now| !now.is_zero()),
+                block_number,
+                schema_version,
+                0,
+                true,
+                expires_at,
+                false,
+                false,
+                transferable,
+                supersedes,
+                None,
+                false,
+                kind,
+                content_type,
+            );
+            Self::note_self_issued(&certification, true);
+            <ListOfCertifications<T, I>>::insert(certification_id, certification);
+            for (key, value) in metadata {
+                <Metadata<T, I>>::insert(certification_id, key, value);
+            }
+            if let Some(old) = supersedes {
+                <ListOfCertifications<T, I>>::mutate(old, |old| {
+                    if let Some(old) = old {
+                        old.superseded_by = Some(certification_id);
+                    }
+                });
+            }
+            <CertificationNonce<T, I>>::put(nonce.wrapping_add(1));
+            <CertificationCount<T, I>>::put(count + 1);
+            <LastCreated<T, I>>::insert(&who, certification_id);
+
+            // Emit an event.
+            Self::deposit_indexed_event(
+                &who,
+                certification_id,
+                Event::CertificationStored {
+                    who: who.clone(),
+                    issuer,
+                    holder: who.clone(),
+                    certification_id,
+                    created_at: block_number,
+                    schema_version,
+                    title: event_title,
+                    description: event_description,
+                },
+            );
+            if let Some(old) = supersedes {
+                Self::deposit_event(Event::CertificationSuperseded {
+                    old,
+                    new: certification_id,
+                });
+            }
+
+            Ok(certification_id)
+        }
+
+        /// Give `who` `n` more certifications, writing storage directly without deposits,
+        /// limits or events, so benchmarks can start from a full owner index.
+        ///
+        /// `who` must be able to hold `n` more certifications.
+        #[cfg(feature = "runtime-benchmarks")]
+        pub fn create_certifications_for(who: &T::AccountId, n: u32) {
+            let block_number = frame_system::Pallet::<T>::block_number();
+            for _ in 0..n {
+                let nonce = <CertificationNonce<T, I>>::get();
+                let certification_id = Self::derive_id(who, nonce);
+                // Distinct titles keep the title index valid if it is enforced.
+                let (title, description) =
+                    Self::bound_content(nonce.to_le_bytes().to_vec(), Vec::new())
+                        .expect("a four byte title is within any sensible limit");
+                Self::insert_into_owner_index(who, certification_id)
+                    .expect("caller ensures the owner has room");
+                Self::insert_into_title_index(who, &title, certification_id)
+                    .expect("titles are distinct");
+                <CertificationsByBlock<T, I>>::insert(block_number, certification_id, ());
+                <CertificationsByKind<T, I>>::insert(
+                    CertificationKind::Other,
+                    certification_id,
+                    (),
+                );
+                Self::record_history(certification_id, HistoryAction::Created);
+                let content_hash = Self::content_hash_of(&title, &description);
+                <ListOfCertifications<T, I>>::insert(
+                    certification_id,
+                    Certification::new(
+                        certification_id,
+                        who.clone(),
+                        who.clone(),
+                        title,
+                        description,
+                        content_hash,
+                        block_number,
+                        None,
+                        block_number,
+                        T::CurrentSchemaVersion::get(),
+                        0,
+                        true,
+                        None,
+                        false,
+                        false,
+                        true,
+                        None,
+                        None,
+                        false,
+                        CertificationKind::Other,
+                        ContentType::PlainText,
+                    ),
+                );
+                <SelfIssuedCount<T, I>>::mutate(who, |count| count.saturating_inc());
+                <CertificationNonce<T, I>>::put(nonce.wrapping_add(1));
+                <CertificationCount<T, I>>::mutate(|count| count.saturating_inc());
+            }
+        }
     }
 }
@@ -0,0 +1,211 @@
+//! Storage migrations of the certification pallet.
+
+/// Version 1 moves the certifications stored by the first release to the current
+/// [`crate::Certification`] layout, keyed by [`crate::CertificationId`], and introduces the
+/// indexes built on top of it, among which [`crate::CertificationsByKind`].
+pub mod v1 {
+    use crate::{
+        Certification, CertificationCount, CertificationIdOf, CertificationKind,
+        CertificationNonce, CertificationsByBlock, CertificationsByKind, Config, ContentType,
+        KindIndexCursor, ListOfCertifications, Pallet,
+    };
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame::{
+        deps::{
+            frame_support::{
+                migrations::VersionedMigration,
+                traits::{OnRuntimeUpgrade, UncheckedOnRuntimeUpgrade},
+            },
+            sp_runtime::WeakBoundedVec,
+        },
+        prelude::*,
+        traits::Saturating,
+    };
+    use scale_info::prelude::vec::Vec;
+
+    #[cfg(feature = "try-runtime")]
+    use frame::deps::sp_runtime::TryRuntimeError;
+
+    /// A certification as stored before version 1, keyed by its plain hash id.
+    #[derive(Encode, Decode)]
+    pub struct OldCertification<AccountId, Hash, BlockNumber> {
+        pub id: Hash,
+        pub owner_id: AccountId,
+        pub title: Vec<u8>,
+        pub description: Vec<u8>,
+        pub created_at: BlockNumber,
+        pub updated_at: BlockNumber,
+    }
+
+    /// [`OldCertification`] for the types of a runtime.
+    pub type OldCertificationOf<T> = OldCertification<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+    >;
+
+    /// Storage reads and writes of indexing a single migrated certification.
+    const READS_PER_CERTIFICATION: u64 = 5;
+    const WRITES_PER_CERTIFICATION: u64 = 8;
+
+    /// Translate every [`OldCertification`] into the current layout, see [`MigrateToV1`].
+    pub struct InnerMigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for InnerMigrateToV1<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated: u32 = 0;
+            <ListOfCertifications<T, I>>::translate::<OldCertificationOf<T>, _>(
+                |certification_id, old| {
+                    migrated.saturating_inc();
+                    Some(Self::migrate(certification_id, old))
+                },
+            );
+            <CertificationCount<T, I>>::put(migrated);
+            // Ids are no longer derived the way the first release derived them, but keep the
+            // nonce ahead of the number of certifications all the same.
+            <CertificationNonce<T, I>>::mutate(|nonce| *nonce = (*nonce).max(migrated));
+
+            log::info!(
+                target: "runtime::certification",
+                "MigrateToV1 migrated {} certification(s)",
+                migrated,
+            );
+            let migrated = u64::from(migrated);
+            T::DbWeight::get().reads_writes(
+                migrated
+                    .saturating_mul(READS_PER_CERTIFICATION)
+                    .saturating_add(1),
+                migrated
+                    .saturating_mul(WRITES_PER_CERTIFICATION)
+                    .saturating_add(2),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((<ListOfCertifications<T, I>>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let before = u32::decode(&mut &state[..])
+                .map_err(|_| "MigrateToV1: cannot decode the pre-upgrade count")?;
+            ensure!(
+                <CertificationCount<T, I>>::get() == before,
+                "MigrateToV1: certifications lost in the migration"
+            );
+            Pallet::<T, I>::do_try_state()
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> InnerMigrateToV1<T, I> {
+        /// The current layout of `old`, indexed like a newly created certification.
+        ///
+        /// The first release had no issuer, kind, content type or expiry, so a migrated
+        /// certification is self-issued, of [`CertificationKind::Other`], plain text, public,
+        /// transferable and never expires. Its `version` starts at 0.
+        fn migrate(
+            certification_id: CertificationIdOf<T>,
+            old: OldCertificationOf<T>,
+        ) -> crate::CertificationOf<T, I> {
+            let title = WeakBoundedVec::force_from(old.title, Some("MigrateToV1 title"));
+            let description =
+                WeakBoundedVec::force_from(old.description, Some("MigrateToV1 description"));
+            let content_hash = Pallet::<T, I>::content_hash_of(&title, &description);
+            let owner = old.owner_id;
+
+            if let Err(error) = Pallet::<T, I>::insert_into_owner_index(&owner, certification_id) {
+                log::error!(
+                    target: "runtime::certification",
+                    "MigrateToV1 could not index certification {:?} for its owner: {:?}",
+                    certification_id,
+                    error,
+                );
+            }
+            if let Err(error) =
+                Pallet::<T, I>::insert_into_title_index(&owner, &title, certification_id)
+            {
+                log::error!(
+                    target: "runtime::certification",
+                    "MigrateToV1 could not index the title of certification {:?}: {:?}",
+                    certification_id,
+                    error,
+                );
+            }
+            <CertificationsByBlock<T, I>>::insert(old.created_at, certification_id, ());
+            <CertificationsByKind<T, I>>::insert(CertificationKind::Other, certification_id, ());
+
+            let certification = Certification::new(
+                certification_id,
+                owner.clone(),
+                owner,
+                title,
+                description,
+                content_hash,
+                old.created_at,
+                None,
+                old.updated_at,
+                0,
+                0,
+                true,
+                None,
+                false,
+                false,
+                true,
+                None,
+                None,
+                false,
+                CertificationKind::Other,
+                ContentType::PlainText,
+            );
+            Pallet::<T, I>::note_self_issued(&certification, true);
+            certification
+        }
+    }
+
+    /// Migrate the certifications of the first release to storage version 1, see
+    /// [`InnerMigrateToV1`].
+    ///
+    /// All certifications are translated in the upgrade block, which the first release's
+    /// small number of certifications allows. Does nothing unless the on-chain storage version
+    /// is 0, and sets it to 1.
+    pub type MigrateToV1<T, I = ()> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateToV1<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Rebuild [`crate::CertificationsByKind`] from [`ListOfCertifications`], e.g. after
+    /// certifications were stored without being indexed by kind.
+    ///
+    /// The upgrade itself only schedules the rebuild by setting [`KindIndexCursor`], and
+    /// `on_idle` then indexes as many certifications as each block allows until all are
+    /// indexed, so the migration cannot overweight a block however many certifications exist.
+    ///
+    /// This does not change the layout of any storage item, so it leaves the storage version
+    /// alone, and [`MigrateToV1`] already indexes the certifications it migrates. Include it in
+    /// the migrations of a single release; it does nothing while a rebuild is pending.
+    pub struct RebuildKindIndex<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for RebuildKindIndex<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            if <KindIndexCursor<T, I>>::exists() {
+                log::info!(
+                    target: "runtime::certification",
+                    "RebuildKindIndex skipped, a rebuild is already pending",
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            <KindIndexCursor<T, I>>::put(None::<CertificationIdOf<T>>);
+            log::info!(
+                target: "runtime::certification",
+                "RebuildKindIndex scheduled, the kind index is rebuilt in on_idle",
+            );
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
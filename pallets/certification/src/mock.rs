@@ -1,8 +1,19 @@
 use frame::{
-    deps::{frame_support::weights::constants::RocksDbWeight, frame_system::GenesisConfig},
+    deps::{
+        frame_support::{
+            instances::Instance1, ord_parameter_types, traits::EitherOfDiverse,
+            weights::constants::RocksDbWeight,
+        },
+        frame_system::{mocking::MockUncheckedExtrinsic, GenesisConfig},
+        sp_runtime::{
+            testing::{TestSignature, UintAuthorityId},
+            traits::Keccak256,
+        },
+    },
     prelude::*,
     runtime::prelude::*,
     testing_prelude::*,
+    traits::Time,
 };
 
 // Configure a mock runtime to test the pallet.
@@ -27,6 +38,43 @@ mod test_runtime {
     pub type System = frame_system;
     #[runtime::pallet_index(1)]
     pub type Template = crate;
+    #[runtime::pallet_index(2)]
+    pub type Issuer = pallet_issuer;
+    #[runtime::pallet_index(3)]
+    pub type Balances = pallet_balances;
+    #[runtime::pallet_index(4)]
+    pub type AcademicRegistry = crate<Instance1>;
+}
+
+/// A minimal pallet issuing certifications through [`crate::CertificationIssuer`], used to test
+/// the pallet from the point of view of another pallet.
+#[frame::pallet(dev_mode)]
+pub mod pallet_issuer {
+    use crate::CertificationIssuer;
+    use frame::prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type CertificationProvider: CertificationIssuer<Self::AccountId, Self::Hash>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        pub fn issue_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            title: Vec<u8>,
+            description: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            T::CertificationProvider::issue(owner, title, description)?;
+            Ok(())
+        }
+    }
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -35,17 +83,189 @@ impl frame_system::Config for Test {
     type Block = MockBlock<Test>;
     type BlockHashCount = ConstU64<250>;
     type DbWeight = RocksDbWeight;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub static MaxCreatesPerWindow: u32 = 100;
+    pub static Now: u64 = 0;
+    pub static CreationCooldown: u64 = 0;
+    pub static HideExistence: bool = false;
+    pub static ArchiveRemoved: bool = false;
+    pub static EmitContentInEvents: bool = false;
+    pub static MaxTotalCertifications: u32 = 1_000;
+    pub static MaxOffchainScan: u32 = 10;
+    pub static SortOwnerIndex: bool = false;
+    pub static MaxTitleLength: u32 = 32;
+    pub static MinTitleLength: u32 = 1;
+    pub static EnforceUniqueTitlePerOwner: bool = false;
+    pub static MaxExpiryChecksPerBlock: u32 = 10;
+    pub static ResetCreatedOnUpdate: bool = false;
+    pub static EmitIndexEvents: bool = false;
+    pub static ContentAddressed: bool = false;
+}
+
+ord_parameter_types! {
+    pub const Admin: u64 = 100;
+}
+
+/// Prices only professional and academic certifications, so other kinds need no funds.
+pub struct MockDeposits;
+impl crate::KindDeposit<u64> for MockDeposits {
+    fn deposit_for(kind: &crate::CertificationKind) -> u64 {
+        match kind {
+            crate::CertificationKind::Professional => 10,
+            crate::CertificationKind::Academic => 5,
+            _ => 0,
+        }
+    }
+}
+
+/// Time source backed by [`Now`], standing in for the timestamp pallet.
+pub struct MockTime;
+impl Time for MockTime {
+    type Moment = u64;
+
+    fn now() -> Self::Moment {
+        Now::get()
+    }
 }
 
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type MaxCertificationsPerOwner = ConstU32<10>;
+    type MaxTransferPerCall = ConstU32<5>;
+    type MaxCreatesPerWindow = MaxCreatesPerWindow;
+    type WindowLength = ConstU64<10>;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type CurrentSchemaVersion = ConstU16<2>;
+    type ForceOrigin =
+        EitherOfDiverse<frame_system::EnsureRoot<u64>, frame_system::EnsureSignedBy<Admin, u64>>;
+    type Time = MockTime;
+    type ContentHasher = Keccak256;
+    type CreationCooldown = CreationCooldown;
+    type HideExistence = HideExistence;
+    type ArchiveRemoved = ArchiveRemoved;
+    type MaxTotalCertifications = MaxTotalCertifications;
+    type MaxMetaKeyLen = ConstU32<16>;
+    type MaxMetaValueLen = ConstU32<32>;
+    type MaxMetadataEntries = ConstU32<2>;
+    type EmitContentInEvents = EmitContentInEvents;
+    type MaxOperators = ConstU32<2>;
+    type ExpiryWarningWindow = ConstU64<10>;
+    type MaxOffchainScan = MaxOffchainScan;
+    type MaxBatchSize = ConstU32<5>;
+    type MaxTitleLength = MaxTitleLength;
+    type MinTitleLength = MinTitleLength;
+    type MaxDescriptionLength = ConstU32<64>;
+    type SortOwnerIndex = SortOwnerIndex;
+    type Currency = Balances;
+    type CertificationDeposit = MockDeposits;
+    type EnforceUniqueTitlePerOwner = EnforceUniqueTitlePerOwner;
+    type ContentAddressed = ContentAddressed;
+    type TombstoneRetention = ConstU64<5>;
+    type MaxExpiryChecksPerBlock = MaxExpiryChecksPerBlock;
+    type MaxReasonLen = ConstU32<16>;
+    type MaxChunkLen = ConstU32<8>;
+    type MaxChunks = ConstU32<3>;
+    type MaxOwnersPerQuery = ConstU32<3>;
+    type ResetCreatedOnUpdate = ResetCreatedOnUpdate;
+    type EmitIndexEvents = EmitIndexEvents;
+    type ReclaimGracePeriod = ConstU64<5>;
+    type MaxExternalIdLen = ConstU32<32>;
+    type MaxEditors = ConstU32<2>;
+    type MaxHistoryEntries = ConstU32<8>;
+    type MaxMergedFrom = ConstU32<2>;
+}
+
+/// A second, independent registry with its own storage and default limits.
+impl crate::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxCertificationsPerOwner = ConstU32<10>;
+    type MaxTransferPerCall = ConstU32<5>;
+    type MaxCreatesPerWindow = ConstU32<100>;
+    type WindowLength = ConstU64<10>;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type CurrentSchemaVersion = ConstU16<1>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type Time = MockTime;
+    type ContentHasher = Keccak256;
+    type CreationCooldown = ConstU64<0>;
+    type HideExistence = ConstBool<false>;
+    type ArchiveRemoved = ConstBool<false>;
+    type MaxTotalCertifications = ConstU32<1_000>;
+    type MaxMetaKeyLen = ConstU32<16>;
+    type MaxMetaValueLen = ConstU32<32>;
+    type MaxMetadataEntries = ConstU32<2>;
+    type EmitContentInEvents = ConstBool<false>;
+    type MaxOperators = ConstU32<2>;
+    type ExpiryWarningWindow = ConstU64<10>;
+    type MaxOffchainScan = ConstU32<10>;
+    type MaxBatchSize = ConstU32<5>;
+    type MaxTitleLength = ConstU32<32>;
+    type MinTitleLength = ConstU32<1>;
+    type MaxDescriptionLength = ConstU32<64>;
+    type SortOwnerIndex = ConstBool<false>;
+    type Currency = Balances;
+    type CertificationDeposit = MockDeposits;
+    type EnforceUniqueTitlePerOwner = ConstBool<false>;
+    type ContentAddressed = ConstBool<false>;
+    type TombstoneRetention = ConstU64<5>;
+    type MaxExpiryChecksPerBlock = ConstU32<10>;
+    type MaxReasonLen = ConstU32<16>;
+    type MaxChunkLen = ConstU32<8>;
+    type MaxChunks = ConstU32<3>;
+    type MaxOwnersPerQuery = ConstU32<3>;
+    type ResetCreatedOnUpdate = ConstBool<false>;
+    type EmitIndexEvents = ConstBool<false>;
+    type ReclaimGracePeriod = ConstU64<5>;
+    type MaxExternalIdLen = ConstU32<32>;
+    type MaxEditors = ConstU32<2>;
+    type MaxHistoryEntries = ConstU32<8>;
+    type MaxMergedFrom = ConstU32<2>;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = MockUncheckedExtrinsic<Test>;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateInherent<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        MockUncheckedExtrinsic::<Test>::new_bare(call)
+    }
+}
+
+impl pallet_issuer::Config for Test {
+    type CertificationProvider = Template;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> TestState {
-    GenesisConfig::<Test>::default()
-        .build_storage()
-        .unwrap()
-        .into()
+    let mut storage = GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    let mut ext: TestState = storage.into();
+    // Events are not deposited on the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
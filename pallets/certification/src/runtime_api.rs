@@ -0,0 +1,49 @@
+//! Runtime API definition for the certification pallet.
+
+use crate::{
+    CertificationDetails, CertificationHeader, CertificationLimits, CertificationStats,
+    CertificationView,
+};
+use codec::Codec;
+use scale_info::prelude::vec::Vec;
+
+frame::deps::sp_api::decl_runtime_apis! {
+    /// Read access to certifications for RPCs and off-chain clients.
+    pub trait CertificationApi<AccountId, Hash, BlockNumber>
+    where
+        AccountId: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// A certification as visible to `caller`. The title and description of a private
+        /// certification are only revealed to its owner.
+        fn get_certification(
+            certification_id: Hash,
+            caller: Option<AccountId>,
+        ) -> Option<CertificationDetails<AccountId, Hash, BlockNumber>>;
+
+        /// A flat view of a certification as visible to `caller`, whose encoding does not
+        /// depend on the runtime's generic types. The title and description of a private
+        /// certification are empty unless `caller` is its owner.
+        fn view(
+            certification_id: Hash,
+            caller: Option<AccountId>,
+        ) -> Option<CertificationView<AccountId, Hash>>;
+
+        /// The certifications of each of `owners`, oldest first, with the title and description of private
+        /// ones left empty. At most `MaxOwnersPerQuery` owners are looked up.
+        fn certifications_of_many(
+            owners: Vec<AccountId>,
+        ) -> Vec<(AccountId, Vec<CertificationView<AccountId, Hash>>)>;
+
+        /// The id, owner, title and creation block of each certification owned by `owner`,
+        /// oldest first, for list views. The title of a private certification is empty.
+        fn certification_headers_of(owner: AccountId) -> Vec<CertificationHeader<AccountId, Hash>>;
+
+        /// Chain-wide certification statistics.
+        fn stats() -> CertificationStats;
+
+        /// The configured limits, such as the maximum title length.
+        fn limits() -> CertificationLimits;
+    }
+}
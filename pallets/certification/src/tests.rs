@@ -1,23 +1,5635 @@
-use crate::{mock::*, Error, Something};
-use frame::testing_prelude::*;
+use crate::{
+    migrations::v1::{MigrateToV1, OldCertification, RebuildKindIndex},
+    mock::*,
+    weights::WeightInfo,
+    ArchivedCertifications, CertificationCount, CertificationCountByOwner, CertificationDeposits,
+    CertificationId, CertificationIdOf, CertificationInspect, CertificationKind,
+    CertificationLimits, CertificationNonce, CertificationReadError, CertificationRequests,
+    CertificationStats, CertificationsByBlock, CertificationsByKind, CertificationsByOwner,
+    CheckCertificationQuota, ConsumedClaims, ContentType, CreationWindow, DescriptionChunks,
+    Editors, Error, EscrowFrozen, ExpiryCheckCursor, ExternalIds, History, HistoryAction,
+    HistoryHead, KindIndexCursor, LastCreatedAt, ListOfCertifications, MergedFrom, Metadata,
+    Operators, PendingTransfers, PruneCursor, RevokedAt, SeenRequests, SelfIssuedCount, TitleIndex,
+    TitlesByOwner, MAX_CREATED_BETWEEN_BLOCKS, MAX_OCW_INDEXED_BLOCKS,
+};
+use frame::{
+    deps::{
+        frame_support::{
+            dispatch::DispatchInfo,
+            instances::Instance1,
+            traits::{GetStorageVersion, OnRuntimeUpgrade, StorageInfoTrait, StorageVersion},
+        },
+        frame_system::mocking::MockUncheckedExtrinsic,
+        sp_core::{
+            offchain::{
+                testing::{TestOffchainExt, TestTransactionPoolExt},
+                OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+            },
+            H256,
+        },
+        sp_runtime::{
+            offchain::storage::StorageValueRef,
+            testing::TestSignature,
+            traits::{BlakeTwo256, Keccak256, TransactionExtension, TxBaseImplication},
+        },
+    },
+    testing_prelude::*,
+    traits::Hash,
+};
+
+type Event = crate::Event<Test>;
+
+/// Hash of the id the next certification created by `who` will get.
+fn next_id(who: u64) -> CertificationIdOf<Test> {
+    Template::derive_id(&who, CertificationNonce::<Test>::get())
+}
+
+/// Create a certification owned by `who` and return its id.
+fn add_cert(who: u64, title: &[u8]) -> CertificationIdOf<Test> {
+    let id = Template::new_id(&who, title, b"description");
+    assert_ok!(Template::add_certification(
+        RuntimeOrigin::signed(who),
+        title.to_vec(),
+        b"description".to_vec(),
+        ContentType::PlainText,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        None,
+        None,
+        None,
+        None,
+        None
+    ));
+    id
+}
+
+#[test]
+fn add_certification_works() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.owner_id, 1);
+        assert_eq!(certification.title, b"title".to_vec());
+        assert_eq!(CertificationNonce::<Test>::get(), 1);
+        System::assert_last_event(
+            Event::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 2,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn do_add_certification_returns_the_stored_id() {
+    new_test_ext().execute_with(|| {
+        let id = Template::do_add_certification(
+            1,
+            3,
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.id, id);
+        assert_eq!(certification.owner_id, 1);
+        assert_eq!(certification.issuer, 3);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), vec![id]);
+    });
+}
+
+#[test]
+fn same_owner_gets_distinct_ids() {
+    new_test_ext().execute_with(|| {
+        let first = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"first".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        let second = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"second".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+
+        assert_ne!(first, second);
+        assert!(ListOfCertifications::<Test>::contains_key(first));
+        assert!(ListOfCertifications::<Test>::contains_key(second));
+    });
+}
+
+#[test]
+fn other_pallet_can_issue_through_trait() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(2);
+        assert_ok!(Issuer::issue_for(
+            RuntimeOrigin::signed(1),
+            2,
+            b"title".to_vec(),
+            b"description".to_vec()
+        ));
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.owner_id, 2);
+        assert_eq!(CertificationNonce::<Test>::get(), 1);
+        System::assert_last_event(
+            Event::CertificationStored {
+                who: 2,
+                issuer: 2,
+                holder: 2,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 2,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn id_collision_is_rejected_on_every_creation_path() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        CertificationNonce::<Test>::put(0);
+        assert_noop!(
+            Issuer::issue_for(
+                RuntimeOrigin::signed(2),
+                1,
+                b"other".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::IdCollision
+        );
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+        assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().owner_id, 1);
+    });
+}
+
+#[test]
+fn add_certification_rejects_existing_id() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+
+        // Rewind the nonce so the next derivation yields the same id again.
+        CertificationNonce::<Test>::put(0);
+        assert_eq!(next_id(1), id);
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"other".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::IdCollision
+        );
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().title,
+            b"title".to_vec()
+        );
+    });
+}
+
+#[test]
+fn add_and_remove_maintain_owner_index() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        let second = add_cert(1, b"second");
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).to_vec(),
+            vec![first, second]
+        );
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            None
+        ));
+        assert_eq!(CertificationsByOwner::<Test>::get(1).to_vec(), vec![second]);
+    });
+}
+
+#[test]
+fn add_certification_respects_owner_cap() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..10 {
+            add_cert(1, b"title");
+        }
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TooManyCertifications
+        );
+    });
+}
+
+#[test]
+fn transfer_all_certifications_works() {
+    new_test_ext().execute_with(|| {
+        let ids = [add_cert(1, b"a"), add_cert(1, b"b"), add_cert(1, b"c")];
+        let kept = add_cert(2, b"d");
+
+        System::set_block_number(3);
+        assert_ok!(Template::transfer_all_certifications(
+            RuntimeOrigin::signed(1),
+            2
+        ));
+
+        assert_eq!(CertificationsByOwner::<Test>::get(1).len(), 0);
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(2).to_vec(),
+            vec![kept, ids[0], ids[1], ids[2]]
+        );
+        for id in ids {
+            assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().owner_id, 2);
+            System::assert_has_event(
+                Event::CertificationTransferred {
+                    from: 1,
+                    to: 2,
+                    certification_id: id,
+                }
+                .into(),
+            );
+        }
+        System::assert_last_event(
+            Event::AllCertificationsTransferred {
+                from: 1,
+                to: 2,
+                count: 3,
+            }
+            .into(),
+        );
+        for id in ids {
+            assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().updated_at, 3);
+        }
+    });
+}
+
+#[test]
+fn transfer_all_certifications_is_bounded() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..6 {
+            add_cert(1, b"title");
+        }
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::TooManyToTransfer
+        );
+    });
+}
+
+#[test]
+fn transfer_all_certifications_is_refunded_to_the_number_moved() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            add_cert(1, b"title");
+        }
+
+        let post_info = Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(Template::transfer_all_weight(2))
+        );
+        assert!(Template::transfer_all_weight(5).any_gt(Template::transfer_all_weight(2)));
+        assert!(Template::transfer_all_weight(2)
+            .any_gt(<() as WeightInfo>::transfer_all_certifications(2)));
+    });
+}
+
+#[test]
+fn transfer_all_certifications_respects_recipient_cap() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            add_cert(1, b"title");
+        }
+        for _ in 0..9 {
+            add_cert(2, b"title");
+        }
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::TooManyCertifications
+        );
+    });
+}
+
+#[test]
+fn add_certification_is_rate_limited_per_window() {
+    new_test_ext().execute_with(|| {
+        MaxCreatesPerWindow::set(3);
+        for _ in 0..3 {
+            add_cert(1, b"title");
+        }
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::RateLimited
+        );
+        // Other accounts have their own window.
+        add_cert(2, b"title");
+
+        // Still inside the window that started at block 1.
+        System::set_block_number(10);
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::RateLimited
+        );
+
+        System::set_block_number(11);
+        add_cert(1, b"title");
+        assert_eq!(CreationWindow::<Test>::get(1), (11, 1));
+    });
+}
+
+#[test]
+fn claim_certification_with_valid_signature() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(2);
+        let payload = Template::claim_payload(&2, b"title", b"description", &1);
+        let signature = TestSignature(1, payload).encode();
+
+        assert_ok!(Template::claim_certification(
+            RuntimeOrigin::signed(2),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            1,
+            signature
+        ));
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.owner_id, 2);
+        assert_eq!(certification.issuer, 1);
+        assert_eq!(CertificationsByOwner::<Test>::get(2).to_vec(), vec![id]);
+    });
+}
+
+#[test]
+fn claim_signature_can_only_be_redeemed_once() {
+    new_test_ext().execute_with(|| {
+        let payload = Template::claim_payload(&2, b"title", b"description", &1);
+        let signature = TestSignature(1, payload.clone()).encode();
+        let claim = |signature: Vec<u8>| {
+            Template::claim_certification(
+                RuntimeOrigin::signed(2),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                1,
+                signature,
+            )
+        };
+
+        assert_ok!(claim(signature.clone()));
+        assert!(ConsumedClaims::<Test>::contains_key(
+            <Test as frame_system::Config>::Hashing::hash(&payload)
+        ));
+        assert_eq!(LastCreatedAt::<Test>::get(2), Some(1));
+
+        System::set_block_number(2);
+        assert_noop!(claim(signature), Error::<Test>::ClaimAlreadyUsed);
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn claim_certification_is_rate_limited() {
+    new_test_ext().execute_with(|| {
+        CreationCooldown::set(5);
+        add_cert(2, b"title");
+
+        let payload = Template::claim_payload(&2, b"claimed", b"description", &1);
+        assert_noop!(
+            Template::claim_certification(
+                RuntimeOrigin::signed(2),
+                b"claimed".to_vec(),
+                b"description".to_vec(),
+                1,
+                TestSignature(1, payload).encode()
+            ),
+            Error::<Test>::CreationTooFrequent
+        );
+    });
+}
+
+#[test]
+fn claim_certification_rejects_tampered_signature() {
+    new_test_ext().execute_with(|| {
+        let payload = Template::claim_payload(&2, b"title", b"description", &1);
+        let signature = TestSignature(1, payload).encode();
+
+        // Content differs from what the issuer signed.
+        assert_noop!(
+            Template::claim_certification(
+                RuntimeOrigin::signed(2),
+                b"forged".to_vec(),
+                b"description".to_vec(),
+                1,
+                signature.clone()
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Signed for another holder.
+        assert_noop!(
+            Template::claim_certification(
+                RuntimeOrigin::signed(3),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                1,
+                signature
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // Not a signature at all.
+        assert_noop!(
+            Template::claim_certification(
+                RuntimeOrigin::signed(2),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                1,
+                vec![1, 2, 3]
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn new_certifications_get_current_schema_version() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id)
+                .unwrap()
+                .schema_version,
+            2
+        );
+    });
+}
+
+#[test]
+fn update_schema_version_works() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+
+        assert_noop!(
+            Template::update_schema_version(RuntimeOrigin::signed(1), id, 3),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Template::update_schema_version(
+                RuntimeOrigin::root(),
+                CertificationId(H256::zero()),
+                3
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+
+        assert_ok!(Template::update_schema_version(
+            RuntimeOrigin::root(),
+            id,
+            3
+        ));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id)
+                .unwrap()
+                .schema_version,
+            3
+        );
+        System::assert_last_event(
+            Event::SchemaVersionUpdated {
+                certification_id: id,
+                schema_version: 3,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn created_time_is_recorded_from_time_source() {
+    new_test_ext().execute_with(|| {
+        // No time source yet.
+        let untimed = add_cert(1, b"title");
+        assert_eq!(Template::created_time(&untimed), None);
+
+        Now::set(1_700_000_000_000);
+        let id = add_cert(1, b"title");
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().created_time,
+            Some(1_700_000_000_000)
+        );
+        assert_eq!(Template::created_time(&id), Some(1_700_000_000_000));
+        assert_eq!(Template::created_time(&CertificationId(H256::zero())), None);
+    });
+}
+
+#[test]
+fn preview_add_matches_created_id() {
+    new_test_ext().execute_with(|| {
+        add_cert(2, b"other");
+        let previewed = Template::preview_add(
+            &1,
+            b"title",
+            b"description",
+            &ContentType::PlainText,
+            &CertificationKind::Other,
+        )
+        .unwrap();
+        // Previewing does not consume the nonce.
+        assert_eq!(
+            Template::preview_add(
+                &1,
+                b"title",
+                b"description",
+                &ContentType::PlainText,
+                &CertificationKind::Other
+            )
+            .unwrap(),
+            previewed
+        );
+
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert!(ListOfCertifications::<Test>::contains_key(previewed));
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).to_vec(),
+            vec![previewed]
+        );
+    });
+}
+
+#[test]
+fn preview_add_reports_failures() {
+    new_test_ext().execute_with(|| {
+        add_cert(3, b"title");
+        CertificationNonce::<Test>::put(0);
+        assert_eq!(
+            Template::preview_add(
+                &3,
+                b"title",
+                b"description",
+                &ContentType::PlainText,
+                &CertificationKind::Other
+            )
+            .map_err(DispatchError::from),
+            Err(Error::<Test>::IdCollision.into())
+        );
+        CertificationNonce::<Test>::put(1);
+
+        MaxCreatesPerWindow::set(1);
+        add_cert(1, b"title");
+        assert_eq!(
+            Template::preview_add(
+                &1,
+                b"title",
+                b"description",
+                &ContentType::PlainText,
+                &CertificationKind::Other
+            )
+            .map_err(DispatchError::from),
+            Err(Error::<Test>::RateLimited.into())
+        );
+    });
+}
+
+#[test]
+fn preview_add_runs_every_add_check() {
+    new_test_ext().execute_with(|| {
+        let preview = |who: u64, title: &[u8], kind: CertificationKind| {
+            Template::preview_add(&who, title, b"description", &ContentType::PlainText, &kind)
+        };
+
+        assert_eq!(
+            preview(1, &[b'a'; 33], CertificationKind::Other),
+            Err(Error::<Test>::TitleTooLong)
+        );
+        assert_eq!(
+            preview(1, b"", CertificationKind::Other),
+            Err(Error::<Test>::TitleTooShort)
+        );
+        assert_eq!(
+            Template::preview_add(
+                &1,
+                b"title",
+                b"not a uri",
+                &ContentType::Uri,
+                &CertificationKind::Other
+            ),
+            Err(Error::<Test>::InvalidUri)
+        );
+
+        EnforceUniqueTitlePerOwner::set(true);
+        add_cert(1, b"title");
+        assert_eq!(
+            preview(1, b"title", CertificationKind::Other),
+            Err(Error::<Test>::DuplicateTitleForOwner)
+        );
+
+        // Account 3 has no balance to reserve a deposit from.
+        assert_eq!(
+            preview(3, b"title", CertificationKind::Professional),
+            Err(Error::<Test>::InsufficientDeposit)
+        );
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(3),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Professional,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::InsufficientDeposit
+        );
+        assert!(preview(3, b"title", CertificationKind::Other).is_ok());
+
+        assert_ok!(Template::set_paused(RuntimeOrigin::root(), true));
+        assert_eq!(
+            preview(2, b"title", CertificationKind::Other),
+            Err(Error::<Test>::PalletPaused)
+        );
+    });
+}
+
+#[test]
+fn content_addressed_ids_reject_duplicate_content() {
+    new_test_ext().execute_with(|| {
+        ContentAddressed::set(true);
+        let id = add_cert(1, b"title");
+        assert_eq!(id, Template::content_id(&1, b"title", b"description"));
+        assert_eq!(
+            Template::preview_add(
+                &1,
+                b"title",
+                b"description",
+                &ContentType::PlainText,
+                &CertificationKind::Other
+            ),
+            Err(Error::<Test>::CertificationAlreadyExists)
+        );
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::CertificationAlreadyExists
+        );
+
+        // Other content, or the same content from another owner, gets its own id.
+        let other = Template::content_id(&1, b"other", b"description");
+        assert_eq!(
+            Template::preview_add(
+                &1,
+                b"other",
+                b"description",
+                &ContentType::PlainText,
+                &CertificationKind::Other
+            ),
+            Ok(other)
+        );
+        assert_ne!(add_cert(2, b"title"), id);
+    });
+}
+
+#[test]
+fn nonce_ids_allow_duplicate_content() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"title");
+        let second = add_cert(1, b"title");
+        assert_ne!(first, second);
+        assert_eq!(Template::count_of(&1), 2);
+    });
+}
+
+#[test]
+fn exists_and_owner_of_work() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let missing = CertificationId(H256::repeat_byte(7));
+
+        assert!(Template::exists(&id));
+        assert!(!Template::exists(&missing));
+        assert_eq!(Template::owner_of(&id), Some(1));
+        assert_eq!(Template::owner_of(&missing), None);
+
+        assert_ok!(Template::transfer_all_certifications(
+            RuntimeOrigin::signed(1),
+            2
+        ));
+        assert_eq!(Template::owner_of(&id), Some(2));
+    });
+}
+
+#[test]
+fn content_hash_uses_content_hasher() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+
+        let content = (&b"title"[..], &b"description"[..]);
+        assert_eq!(certification.content_hash, Keccak256::hash_of(&content));
+        assert_ne!(certification.content_hash, BlakeTwo256::hash_of(&content));
+        assert_ne!(certification.content_hash, id);
+        assert_eq!(
+            Template::content_hash_of(b"title", b"description"),
+            certification.content_hash
+        );
+
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().content_hash,
+            Keccak256::hash_of(&(&b"new title"[..], &b"description"[..]))
+        );
+    });
+}
+
+#[test]
+fn add_certification_enforces_cooldown() {
+    new_test_ext().execute_with(|| {
+        CreationCooldown::set(5);
+        add_cert(1, b"title");
+        assert_eq!(LastCreatedAt::<Test>::get(1), Some(1));
+
+        System::set_block_number(5);
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::CreationTooFrequent
+        );
+        // The cooldown is tracked per account.
+        add_cert(2, b"title");
+
+        System::set_block_number(6);
+        add_cert(1, b"title");
+        assert_eq!(LastCreatedAt::<Test>::get(1), Some(6));
+    });
+}
+
+#[test]
+fn owner_gated_calls_distinguish_missing_and_not_owned() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let missing = CertificationId(H256::repeat_byte(7));
+
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                missing,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(2),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Template::remove_certification(RuntimeOrigin::signed(1), missing, None),
+            Error::<Test>::CertificationNotFound
+        );
+        assert_noop!(
+            Template::remove_certification(RuntimeOrigin::signed(2), id, None),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn granted_editor_can_update_until_revoked() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::grant_editor(RuntimeOrigin::signed(2), id, 2),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::grant_editor(RuntimeOrigin::signed(1), id, 2));
+        System::assert_last_event(
+            Event::EditorGranted {
+                certification_id: id,
+                editor: 2,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Template::grant_editor(RuntimeOrigin::signed(1), id, 2),
+            Error::<Test>::AlreadyEditor
+        );
+
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(2),
+            id,
+            b"edited".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.title.to_vec(), b"edited".to_vec());
+        // The editor does not become the owner.
+        assert_eq!(certification.owner_id, 1);
+        assert_eq!(Template::titles_sorted(&1)[0].0, b"edited".to_vec());
+
+        assert_ok!(Template::revoke_editor(RuntimeOrigin::signed(1), id, 2));
+        System::assert_last_event(
+            Event::EditorRevoked {
+                certification_id: id,
+                editor: 2,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(2),
+                id,
+                b"again".to_vec(),
+                b"description".to_vec(),
+                1,
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Template::revoke_editor(RuntimeOrigin::signed(1), id, 2),
+            Error::<Test>::NotEditor
+        );
+    });
+}
+
+#[test]
+fn owner_cannot_be_granted_editor() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::grant_editor(RuntimeOrigin::signed(1), id, 1),
+            Error::<Test>::OwnerIsEditor
+        );
+    });
+}
+
+#[test]
+fn editors_lose_access_when_ownership_changes() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::grant_editor(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 3));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(3), id));
+
+        assert_eq!(Editors::<Test>::iter_prefix(id).count(), 0);
+        assert_noop!(
+            Template::revoke_editor(RuntimeOrigin::signed(3), id, 2),
+            Error::<Test>::NotEditor
+        );
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(2),
+                id,
+                b"edited".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::NotAuthorized
+        );
+
+        // The same holds for a forced reassignment.
+        assert_ok!(Template::grant_editor(RuntimeOrigin::signed(3), id, 2));
+        assert_ok!(Template::reassign_owner(RuntimeOrigin::root(), id, 1));
+        assert_noop!(
+            Template::revoke_editor(RuntimeOrigin::signed(1), id, 2),
+            Error::<Test>::NotEditor
+        );
+    });
+}
+
+#[test]
+fn editors_are_bounded_and_cleared_on_removal() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::grant_editor(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::grant_editor(RuntimeOrigin::signed(1), id, 3));
+        assert_noop!(
+            Template::grant_editor(RuntimeOrigin::signed(1), id, 4),
+            Error::<Test>::TooManyEditors
+        );
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert_eq!(Editors::<Test>::iter_prefix(id).count(), 0);
+    });
+}
+
+#[test]
+fn hide_existence_returns_opaque_error() {
+    new_test_ext().execute_with(|| {
+        HideExistence::set(true);
+        let id = add_cert(1, b"title");
+        let missing = CertificationId(H256::repeat_byte(7));
+
+        for (who, target) in [(1, missing), (2, id)] {
+            assert_noop!(
+                Template::update_certification(
+                    RuntimeOrigin::signed(who),
+                    target,
+                    b"title".to_vec(),
+                    b"description".to_vec(),
+                    0,
+                    None
+                ),
+                Error::<Test>::AccessDenied
+            );
+            assert_noop!(
+                Template::remove_certification(RuntimeOrigin::signed(who), target, None),
+                Error::<Test>::AccessDenied
+            );
+        }
+
+        // The owner is unaffected.
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+    });
+}
+
+#[test]
+fn update_with_matching_version_increments_it() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().version, 0);
+
+        System::set_block_number(2);
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.version, 1);
+        assert_eq!(certification.title, b"new title".to_vec());
+        assert_eq!(certification.updated_at, 2);
+        System::assert_last_event(
+            Event::CertificationUpdated {
+                who: 1,
+                certification_id: id,
+                updated_at: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn update_checks_the_expected_update_block() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let update = |version, expected_updated_at| {
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                version,
+                expected_updated_at,
+            )
+        };
+
+        System::set_block_number(3);
+        assert_ok!(update(0, Some(1)));
+        assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().updated_at, 3);
+
+        // Another client still expects the creation block.
+        System::set_block_number(4);
+        assert_noop!(update(1, Some(1)), Error::<Test>::StaleUpdate);
+
+        assert_ok!(update(1, None));
+        assert_ok!(update(2, Some(4)));
+    });
+}
+
+#[test]
+fn update_only_touches_content_fields() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::set_visibility(
+            RuntimeOrigin::signed(1),
+            id,
+            false
+        ));
+        let before = ListOfCertifications::<Test>::get(id).unwrap();
+
+        System::set_block_number(3);
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"new description".to_vec(),
+            0,
+            None
+        ));
+
+        let after = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(after.description, b"new description".to_vec());
+        assert_eq!(
+            after.content_hash,
+            Template::content_hash_of(b"new title", b"new description")
+        );
+        assert_eq!(after.owner_id, before.owner_id);
+        assert_eq!(after.issuer, before.issuer);
+        assert_eq!(after.created_at, before.created_at);
+        assert_eq!(after.schema_version, before.schema_version);
+        assert_eq!(after.public, before.public);
+    });
+}
+
+#[test]
+fn update_resets_creation_only_if_configured() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let update = |version| {
+            assert_ok!(Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                version,
+                None
+            ));
+        };
+
+        System::set_block_number(3);
+        update(0);
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!((certification.created_at, certification.updated_at), (1, 3));
+
+        ResetCreatedOnUpdate::set(true);
+        Now::set(42);
+        System::set_block_number(5);
+        update(1);
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!((certification.created_at, certification.updated_at), (5, 5));
+        assert_eq!(Template::created_time(&id), Some(42));
+        assert!(Template::certifications_created_at(1).is_empty());
+        assert_eq!(Template::certifications_created_at(5), vec![id]);
+    });
+}
+
+#[test]
+fn update_with_stale_version_fails() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"first edit".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+
+        // A second client still holding version 0.
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"second edit".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::VersionMismatch
+        );
+    });
+}
+
+#[test]
+fn removed_certifications_are_dropped_by_default() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+
+        assert!(!ListOfCertifications::<Test>::contains_key(id));
+        assert!(!ArchivedCertifications::<Test>::contains_key(id));
+    });
+}
+
+#[test]
+fn removed_certifications_are_archived_and_purgeable() {
+    new_test_ext().execute_with(|| {
+        ArchiveRemoved::set(true);
+        let first = add_cert(1, b"first");
+        let second = add_cert(1, b"second");
+        let stored = ListOfCertifications::<Test>::get(first).unwrap();
+
+        System::set_block_number(3);
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            None
+        ));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            second,
+            None
+        ));
+
+        assert!(!ListOfCertifications::<Test>::contains_key(first));
+        assert!(CertificationsByOwner::<Test>::get(1).is_empty());
+        let archived = ArchivedCertifications::<Test>::get(first).unwrap();
+        assert!(archived.certification == stored);
+        assert_eq!(archived.removed_at, 3);
+
+        assert_noop!(
+            Template::purge_archive(RuntimeOrigin::signed(1), 10),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::purge_archive(RuntimeOrigin::root(), 10));
+        assert!(!ArchivedCertifications::<Test>::contains_key(first));
+        assert!(!ArchivedCertifications::<Test>::contains_key(second));
+        System::assert_last_event(Event::ArchivePurged { removed: 2 }.into());
+    });
+}
+
+#[test]
+fn private_certification_hides_content_from_others() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let details = Template::get_certification(&id, None).unwrap();
+        assert_eq!(details.title, Some(b"title".to_vec()));
+        assert!(details.public);
+
+        assert_noop!(
+            Template::set_visibility(RuntimeOrigin::signed(2), id, false),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::set_visibility(
+            RuntimeOrigin::signed(1),
+            id,
+            false
+        ));
+        System::assert_last_event(
+            Event::VisibilityChanged {
+                certification_id: id,
+                public: false,
+            }
+            .into(),
+        );
+
+        for caller in [None, Some(&2)] {
+            let details = Template::get_certification(&id, caller).unwrap();
+            assert_eq!(details.owner_id, 1);
+            assert!(!details.public);
+            assert_eq!(details.title, None);
+            assert_eq!(details.description, None);
+        }
+
+        let details = Template::get_certification(&id, Some(&1)).unwrap();
+        assert_eq!(details.title, Some(b"title".to_vec()));
+        assert_eq!(details.description, Some(b"description".to_vec()));
+
+        assert_eq!(
+            Template::get_certification(&CertificationId(H256::zero()), Some(&1)),
+            None
+        );
+    });
+}
+
+#[test]
+fn get_certification_or_err_maps_absence_to_an_error() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_eq!(
+            Template::get_certification_or_err(&id).map(|certification| certification.owner_id),
+            Ok(1)
+        );
+
+        let missing = CertificationId(H256::zero());
+        let error = Template::get_certification_or_err(&missing)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(error, Error::<Test>::CertificationNotFound.into());
+        assert!(matches!(error, DispatchError::Module(_)));
+    });
+}
+
+#[test]
+fn two_step_transfer_works() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+
+        assert_noop!(
+            Template::offer_transfer(RuntimeOrigin::signed(2), id, 2),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_eq!(PendingTransfers::<Test>::get(id), Some(2));
+        System::assert_last_event(
+            Event::TransferOffered {
+                certification_id: id,
+                from: 1,
+                to: 2,
+            }
+            .into(),
+        );
+        // Nothing moves until the recipient accepts.
+        assert_eq!(Template::owner_of(&id), Some(1));
+
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_eq!(Template::owner_of(&id), Some(2));
+        assert!(CertificationsByOwner::<Test>::get(1).is_empty());
+        assert_eq!(CertificationsByOwner::<Test>::get(2).to_vec(), vec![id]);
+        assert_eq!(PendingTransfers::<Test>::get(id), None);
+        System::assert_has_event(
+            Event::CertificationTransferred {
+                from: 1,
+                to: 2,
+                certification_id: id,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::TransferAccepted {
+                certification_id: id,
+                from: 1,
+                to: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn only_recipient_can_accept_transfer() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::accept_transfer(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NoPendingTransfer
+        );
+
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_noop!(
+            Template::accept_transfer(RuntimeOrigin::signed(3), id),
+            Error::<Test>::NotTransferRecipient
+        );
+        assert_eq!(Template::owner_of(&id), Some(1));
+    });
+}
+
+#[test]
+fn owner_can_cancel_transfer() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+
+        assert_noop!(
+            Template::cancel_transfer(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::cancel_transfer(RuntimeOrigin::signed(1), id));
+        System::assert_last_event(
+            Event::TransferCancelled {
+                certification_id: id,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Template::accept_transfer(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NoPendingTransfer
+        );
+        assert_noop!(
+            Template::cancel_transfer(RuntimeOrigin::signed(1), id),
+            Error::<Test>::NoPendingTransfer
+        );
+    });
+}
+
+#[test]
+fn global_cap_limits_total_certifications() {
+    new_test_ext().execute_with(|| {
+        MaxTotalCertifications::set(3);
+        let first = add_cert(1, b"title");
+        add_cert(2, b"title");
+        add_cert(3, b"title");
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(4),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::GlobalCapReached
+        );
+        assert_noop!(
+            Issuer::issue_for(
+                RuntimeOrigin::signed(4),
+                4,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::GlobalCapReached
+        );
+
+        // A removal frees a slot.
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            None
+        ));
+        assert_eq!(CertificationCount::<Test>::get(), 2);
+        add_cert(4, b"title");
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+    });
+}
+
+fn batch(items: &[&[u8]]) -> BoundedVec<(Vec<u8>, Vec<u8>), <Test as crate::Config>::MaxBatchSize> {
+    BoundedVec::truncate_from(
+        items
+            .iter()
+            .map(|title| (title.to_vec(), b"description".to_vec()))
+            .collect(),
+    )
+}
+
+#[test]
+fn add_certifications_creates_each_item() {
+    new_test_ext().execute_with(|| {
+        let first = next_id(1);
+        let second = Template::derive_id(&1, CertificationNonce::<Test>::get() + 1);
+        let post_info =
+            Template::add_certifications(RuntimeOrigin::signed(1), batch(&[b"first", b"second"]))
+                .unwrap();
+        // Charged for the full batch, and refunded down to its actual size.
+        assert_eq!(post_info.actual_weight, Some(Template::add_batch_weight(2)));
+
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).into_inner(),
+            vec![first, second]
+        );
+        assert_eq!(
+            ListOfCertifications::<Test>::get(second)
+                .unwrap()
+                .title
+                .to_vec(),
+            b"second".to_vec()
+        );
+        assert_eq!(CertificationCount::<Test>::get(), 2);
+        assert_eq!(LastCreatedAt::<Test>::get(1), Some(1));
+    });
+}
+
+#[test]
+fn add_certifications_rolls_back_on_a_failing_item() {
+    new_test_ext().execute_with(|| {
+        add_cert(2, b"title");
+        let nonce = CertificationNonce::<Test>::get();
+        let count = CertificationCount::<Test>::get();
+
+        // The last title is too long.
+        let result = Template::add_certifications(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"b", &[b'c'; 33]]),
+        );
+        assert_eq!(
+            result.map_err(|error| error.error),
+            Err(Error::<Test>::TitleTooLong.into())
+        );
+
+        assert_eq!(CertificationNonce::<Test>::get(), nonce);
+        assert_eq!(CertificationCount::<Test>::get(), count);
+        assert_eq!(Template::count_of(&1), 0);
+        assert!(TitlesByOwner::<Test>::iter_prefix(1).next().is_none());
+        assert_eq!(CreationWindow::<Test>::get(1), Default::default());
+    });
+}
+
+#[test]
+fn add_certifications_respects_the_global_cap() {
+    new_test_ext().execute_with(|| {
+        MaxTotalCertifications::set(3);
+        add_cert(2, b"title");
+
+        // The whole batch must fit.
+        assert_noop!(
+            Template::add_certifications(RuntimeOrigin::signed(1), batch(&[b"a", b"b", b"c"])),
+            Error::<Test>::GlobalCapReached
+        );
+        assert_ok!(Template::add_certifications(
+            RuntimeOrigin::signed(1),
+            batch(&[b"a", b"b"])
+        ));
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+        assert_noop!(
+            Template::add_certifications(RuntimeOrigin::signed(1), batch(&[b"c"])),
+            Error::<Test>::GlobalCapReached
+        );
+
+        // A removal frees a slot.
+        let removed = Template::certifications_of(&1)[0];
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            removed,
+            None
+        ));
+        assert_ok!(Template::add_certifications(
+            RuntimeOrigin::signed(1),
+            batch(&[b"c"])
+        ));
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+    });
+}
+
+#[test]
+fn global_cap_applies_to_operator_and_reissue_paths() {
+    new_test_ext().execute_with(|| {
+        MaxTotalCertifications::set(2);
+        let first = add_cert(1, b"first");
+        add_cert(1, b"second");
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 2));
+
+        assert_noop!(
+            Template::add_certification_for(
+                RuntimeOrigin::signed(2),
+                1,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::GlobalCapReached
+        );
+        assert_noop!(
+            Template::reissue_certification(
+                RuntimeOrigin::signed(1),
+                first,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::GlobalCapReached
+        );
+    });
+}
+
+/// Bounded byte string for metadata keys and values.
+fn bounded<S: Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+    bytes.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn metadata_can_be_set_at_creation() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(1);
+        let metadata: BoundedVec<_, _> =
+            vec![(bounded(b"grade"), bounded(b"A"))].try_into().unwrap();
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            metadata.clone(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert_eq!(Template::metadata_of(&id), metadata.into_inner());
+    });
+}
+
+#[test]
+fn set_overwrite_and_clear_metadata() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let metadata = || Template::metadata_of(&id);
+
+        assert_noop!(
+            Template::set_metadata(
+                RuntimeOrigin::signed(2),
+                id,
+                bounded(b"grade"),
+                bounded(b"A")
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            id,
+            bounded(b"grade"),
+            bounded(b"A")
+        ));
+        System::assert_last_event(
+            Event::MetadataSet {
+                certification_id: id,
+                key: bounded(b"grade"),
+            }
+            .into(),
+        );
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            id,
+            bounded(b"score"),
+            bounded(b"97")
+        ));
+
+        // Overwriting does not count against the per-certification key cap.
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            id,
+            bounded(b"grade"),
+            bounded(b"B")
+        ));
+        assert_eq!(
+            metadata(),
+            vec![
+                (bounded(b"grade"), bounded(b"B")),
+                (bounded(b"score"), bounded(b"97"))
+            ]
+        );
+        assert_noop!(
+            Template::set_metadata(
+                RuntimeOrigin::signed(1),
+                id,
+                bounded(b"code"),
+                bounded(b"X1")
+            ),
+            Error::<Test>::TooManyMetadataEntries
+        );
+
+        assert_ok!(Template::clear_metadata(
+            RuntimeOrigin::signed(1),
+            id,
+            bounded(b"grade")
+        ));
+        System::assert_last_event(
+            Event::MetadataCleared {
+                certification_id: id,
+                key: bounded(b"grade"),
+            }
+            .into(),
+        );
+        assert_eq!(metadata(), vec![(bounded(b"score"), bounded(b"97"))]);
+        assert_noop!(
+            Template::clear_metadata(RuntimeOrigin::signed(1), id, bounded(b"grade")),
+            Error::<Test>::MetadataKeyNotFound
+        );
+    });
+}
+
+#[test]
+fn certifications_are_indexed_by_creation_block() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        let second = add_cert(2, b"second");
+        System::set_block_number(2);
+        let third = add_cert(1, b"third");
+
+        let mut created_at_one = Template::certifications_created_at(1);
+        created_at_one.sort();
+        let mut expected = vec![first, second];
+        expected.sort();
+        assert_eq!(created_at_one, expected);
+        assert_eq!(Template::certifications_created_at(2), vec![third]);
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            None
+        ));
+        assert_eq!(Template::certifications_created_at(1), vec![second]);
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            second,
+            None
+        ));
+        assert!(Template::certifications_created_at(1).is_empty());
+        assert_eq!(CertificationsByBlock::<Test>::iter_prefix(1).count(), 0);
+    });
+}
+
+#[test]
+fn created_between_returns_certifications_in_range() {
+    new_test_ext().execute_with(|| {
+        let ids: Vec<_> = (1..=5)
+            .map(|block| {
+                System::set_block_number(block);
+                add_cert(1, b"title")
+            })
+            .collect();
+
+        let mut found = Template::created_between(2, 4);
+        found.sort();
+        let mut expected = ids[1..4].to_vec();
+        expected.sort();
+        assert_eq!(found, expected);
+
+        assert_eq!(Template::created_between(5, 100), vec![ids[4]]);
+        assert!(Template::created_between(4, 2).is_empty());
+        assert!(Template::created_between(6, 10).is_empty());
+    });
+}
+
+#[test]
+fn created_between_scans_a_bounded_range() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"title");
+        System::set_block_number(1 + MAX_CREATED_BETWEEN_BLOCKS as u64);
+        add_cert(1, b"title");
+
+        // The second certification is one block past the scanned range.
+        assert_eq!(Template::created_between(1, u64::MAX), vec![first]);
+    });
+}
+
+#[test]
+fn metadata_is_cleared_with_the_certification() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            id,
+            bounded(b"grade"),
+            bounded(b"A")
+        ));
+        assert_eq!(Metadata::<Test>::iter_prefix(id).count(), 1);
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert_eq!(Metadata::<Test>::iter_prefix(id).count(), 0);
+    });
+}
+
+#[test]
+fn stored_event_carries_content_only_when_enabled() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        System::assert_last_event(
+            Event::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 2,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+
+        EmitContentInEvents::set(true);
+        let id = add_cert(1, b"title");
+        System::assert_last_event(
+            Event::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 2,
+                title: Some(b"title".to_vec()),
+                description: Some(b"description".to_vec()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn add_certification_with_repeated_request_id_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let request_id = [7u8; 32];
+        let id = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            Some(request_id),
+            None
+        ));
+        assert_eq!(SeenRequests::<Test>::get(1, request_id), Some(id));
+
+        // A retry of the same submission does not create a second certification.
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                Some(request_id),
+                None
+            ),
+            Error::<Test>::DuplicateRequest
+        );
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), vec![id]);
+
+        // Request ids are scoped to the caller.
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(2),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            Some(request_id),
+            None
+        ));
+        assert_eq!(CertificationCount::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn removal_clears_the_request_id() {
+    new_test_ext().execute_with(|| {
+        let request_id = [7u8; 32];
+        let id = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"title".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            Some(request_id),
+            None
+        ));
+        assert_eq!(
+            CertificationRequests::<Test>::get(id),
+            Some((1, request_id))
+        );
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert!(!SeenRequests::<Test>::contains_key(1, request_id));
+        assert!(!CertificationRequests::<Test>::contains_key(id));
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn operator_can_create_on_behalf_of_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 5));
+        System::assert_last_event(
+            Event::OperatorAuthorized {
+                owner: 1,
+                operator: 5,
+            }
+            .into(),
+        );
+        assert_eq!(Operators::<Test>::get(1).into_inner(), vec![5]);
+
+        let id = next_id(1);
+        assert_ok!(Template::add_certification_for(
+            RuntimeOrigin::signed(5),
+            1,
+            b"title".to_vec(),
+            b"description".to_vec()
+        ));
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.owner_id, 1);
+        assert_eq!(certification.issuer, 5);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), vec![id]);
+        assert!(CertificationsByOwner::<Test>::get(5).is_empty());
+    });
+}
+
+#[test]
+fn operator_creations_respect_the_owner_cooldown() {
+    new_test_ext().execute_with(|| {
+        CreationCooldown::set(5);
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 5));
+        let add_for = || {
+            Template::add_certification_for(
+                RuntimeOrigin::signed(5),
+                1,
+                b"title".to_vec(),
+                b"description".to_vec(),
+            )
+        };
+
+        assert_ok!(add_for());
+        assert_eq!(LastCreatedAt::<Test>::get(1), Some(1));
+
+        System::set_block_number(5);
+        assert_noop!(add_for(), Error::<Test>::CreationTooFrequent);
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::CreationTooFrequent
+        );
+
+        System::set_block_number(6);
+        assert_ok!(add_for());
+    });
+}
+
+#[test]
+fn stored_event_carries_issuer_and_holder() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 5));
+        let id = next_id(1);
+        assert_ok!(Template::add_certification_for(
+            RuntimeOrigin::signed(5),
+            1,
+            b"title".to_vec(),
+            b"description".to_vec()
+        ));
+
+        let encoded = System::events().last().unwrap().event.encode();
+        let RuntimeEvent::Template(Event::CertificationStored {
+            who,
+            issuer,
+            holder,
+            certification_id,
+            ..
+        }) = RuntimeEvent::decode(&mut &encoded[..]).unwrap()
+        else {
+            panic!("the last event is CertificationStored");
+        };
+        assert_eq!(certification_id, id);
+        assert_eq!(issuer, 5);
+        assert_eq!(holder, 1);
+        // `who` keeps naming the holder.
+        assert_eq!(who, 1);
+    });
+}
+
+#[test]
+fn self_issued_certifications_are_counted_per_owner() {
+    new_test_ext().execute_with(|| {
+        let own = add_cert(1, b"Own");
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 5));
+        let issued = next_id(1);
+        assert_ok!(Template::add_certification_for(
+            RuntimeOrigin::signed(5),
+            1,
+            b"Issued".to_vec(),
+            b"description".to_vec()
+        ));
+
+        assert!(ListOfCertifications::<Test>::get(own)
+            .unwrap()
+            .is_self_issued());
+        assert!(!ListOfCertifications::<Test>::get(issued)
+            .unwrap()
+            .is_self_issued());
+        assert_eq!(SelfIssuedCount::<Test>::get(1), 1);
+
+        // Once transferred, the certification is no longer held by its issuer.
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), own, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), own));
+        assert!(!ListOfCertifications::<Test>::get(own)
+            .unwrap()
+            .is_self_issued());
+        assert_eq!(SelfIssuedCount::<Test>::get(1), 0);
+        assert_eq!(SelfIssuedCount::<Test>::get(2), 0);
+
+        let other = add_cert(2, b"Other");
+        assert_eq!(SelfIssuedCount::<Test>::get(2), 1);
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            other,
+            None
+        ));
+        assert!(!SelfIssuedCount::<Test>::contains_key(2));
+    });
+}
+
+#[test]
+fn unauthorized_operator_cannot_create() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Template::add_certification_for(
+                RuntimeOrigin::signed(5),
+                1,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::NotAuthorizedOperator
+        );
+
+        // Authorization is per owner, and can be revoked.
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(2), 5));
+        assert_noop!(
+            Template::add_certification_for(
+                RuntimeOrigin::signed(5),
+                1,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::NotAuthorizedOperator
+        );
+        assert_ok!(Template::revoke_operator(RuntimeOrigin::signed(2), 5));
+        System::assert_last_event(
+            Event::OperatorRevoked {
+                owner: 2,
+                operator: 5,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Template::add_certification_for(
+                RuntimeOrigin::signed(5),
+                2,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::NotAuthorizedOperator
+        );
+        assert_noop!(
+            Template::revoke_operator(RuntimeOrigin::signed(2), 5),
+            Error::<Test>::NotAuthorizedOperator
+        );
+    });
+}
+
+#[test]
+fn operators_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 5));
+        assert_noop!(
+            Template::authorize_operator(RuntimeOrigin::signed(1), 5),
+            Error::<Test>::OperatorAlreadyAuthorized
+        );
+        assert_ok!(Template::authorize_operator(RuntimeOrigin::signed(1), 6));
+        assert_noop!(
+            Template::authorize_operator(RuntimeOrigin::signed(1), 7),
+            Error::<Test>::TooManyOperators
+        );
+    });
+}
+
+/// Create a certification owned by `who` that expires at `expires_at`, and return its id.
+fn add_expiring_cert(who: u64, expires_at: u64) -> CertificationIdOf<Test> {
+    let id = next_id(who);
+    assert_ok!(Template::add_certification(
+        RuntimeOrigin::signed(who),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        ContentType::PlainText,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        Some(expires_at),
+        None,
+        None,
+        None,
+        None
+    ));
+    id
+}
+
+#[test]
+fn expiry_must_be_in_the_future() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                Some(1),
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::InvalidExpiry
+        );
+        let id = add_expiring_cert(1, 2);
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().expires_at,
+            Some(2)
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_reports_expiring_certifications() {
+    let (offchain, _offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let expiring = add_expiring_cert(1, 5);
+        let later = add_expiring_cert(1, 50);
+        add_cert(1, b"title");
+
+        Template::offchain_worker(1);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = MockUncheckedExtrinsic::<Test>::decode(&mut &*tx).unwrap();
+        let RuntimeCall::Template(call) = tx.function else {
+            panic!("unexpected call");
+        };
+        let crate::Call::report_expiring { certification_ids } = call.clone() else {
+            panic!("unexpected call");
+        };
+        assert_eq!(certification_ids.to_vec(), vec![expiring]);
+
+        assert_ok!(Template::validate_unsigned(TransactionSource::Local, &call));
+        assert_ok!(Template::report_expiring(
+            RuntimeOrigin::none(),
+            certification_ids
+        ));
+        System::assert_last_event(
+            Event::CertificationExpiringSoon {
+                certification_id: expiring,
+                expires_at: 5,
+            }
+            .into(),
+        );
+
+        // Reports of certifications that are not expiring soon are rejected.
+        let stale = crate::Call::report_expiring {
+            certification_ids: vec![later].try_into().unwrap(),
+        };
+        assert!(Template::validate_unsigned(TransactionSource::External, &stale).is_err());
+    });
+}
+
+#[test]
+fn offchain_scan_is_bounded_and_resumes() {
+    new_test_ext().execute_with(|| {
+        MaxOffchainScan::set(2);
+        let mut ids: Vec<_> = (0..3).map(|_| add_expiring_cert(1, 5)).collect();
+
+        let (mut found, cursor, _) = Template::scan_expiring(None, 1, MaxOffchainScan::get());
+        assert_eq!(found.len(), 2);
+        let start = ListOfCertifications::<Test>::hashed_key_for(cursor.unwrap());
+
+        let (rest, cursor, scanned) =
+            Template::scan_expiring(Some(start), 1, MaxOffchainScan::get());
+        assert_eq!(scanned, 1);
+        assert_eq!(rest.len(), 1);
+        // The end was reached, so the next scan starts over.
+        assert_eq!(cursor, None);
+
+        found.extend(rest);
+        found.sort();
+        ids.sort();
+        assert_eq!(found, ids);
+    });
+}
+
+#[test]
+fn frozen_certification_rejects_mutations() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::freeze_certification(RuntimeOrigin::signed(1), id),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Template::freeze_certification(
+                RuntimeOrigin::root(),
+                CertificationId(H256::repeat_byte(7))
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::freeze_certification(RuntimeOrigin::root(), id));
+        System::assert_last_event(
+            Event::CertificationFrozen {
+                certification_id: id,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"new title".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_noop!(
+            Template::remove_certification(RuntimeOrigin::signed(1), id, None),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_noop!(
+            Template::offer_transfer(RuntimeOrigin::signed(1), id, 3),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_noop!(
+            Template::accept_transfer(RuntimeOrigin::signed(2), id),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 3),
+            Error::<Test>::CertificationFrozen
+        );
+    });
+}
+
+#[test]
+fn transfer_and_freeze_locks_the_recipient_until_unfrozen() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::transfer_and_freeze(RuntimeOrigin::signed(2), id, 3),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::transfer_and_freeze(
+            RuntimeOrigin::signed(1),
+            id,
+            2
+        ));
+        let events: Vec<_> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .rev()
+            .take(2)
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                RuntimeEvent::Template(Event::CertificationFrozen {
+                    certification_id: id
+                }),
+                RuntimeEvent::Template(Event::CertificationTransferred {
+                    from: 1,
+                    to: 2,
+                    certification_id: id
+                }),
+            ]
+        );
+        assert_eq!(Template::owner_of(&id), Some(2));
+
+        let update = |who| {
+            Template::update_certification(
+                RuntimeOrigin::signed(who),
+                id,
+                b"new title".to_vec(),
+                b"description".to_vec(),
+                0,
+                None,
+            )
+        };
+        assert_noop!(update(2), Error::<Test>::CertificationFrozen);
+        assert_noop!(
+            Template::unfreeze_certification(RuntimeOrigin::signed(1), id),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::unfreeze_certification(
+            RuntimeOrigin::signed(2),
+            id
+        ));
+        assert!(!EscrowFrozen::<Test>::contains_key(id));
+        assert_ok!(update(2));
+    });
+}
+
+#[test]
+fn owner_cannot_lift_a_dispute_freeze() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::transfer_and_freeze(
+            RuntimeOrigin::signed(1),
+            id,
+            2
+        ));
+        assert_ok!(Template::freeze_certification(RuntimeOrigin::root(), id));
+        assert_noop!(
+            Template::unfreeze_certification(RuntimeOrigin::signed(2), id),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::unfreeze_certification(RuntimeOrigin::root(), id));
+    });
+}
+
+#[test]
+fn unfreezing_restores_mutations() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::freeze_certification(RuntimeOrigin::root(), id));
+        assert_ok!(Template::unfreeze_certification(RuntimeOrigin::root(), id));
+        System::assert_last_event(
+            Event::CertificationUnfrozen {
+                certification_id: id,
+            }
+            .into(),
+        );
+        assert!(!ListOfCertifications::<Test>::get(id).unwrap().frozen);
+
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            id,
+            None
+        ));
+    });
+}
+
+#[test]
+fn derive_id_predicts_the_first_certification_id() {
+    new_test_ext().execute_with(|| {
+        let expected = Template::derive_id(&1, 0);
+        assert_eq!(
+            expected,
+            CertificationId(<Test as frame_system::Config>::Hashing::hash_of(&(
+                &1u64, 0u32
+            )))
+        );
+
+        add_cert(1, b"title");
+        System::assert_has_event(
+            Event::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: expected,
+                created_at: 1,
+                schema_version: 2,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+        assert!(ListOfCertifications::<Test>::contains_key(expected));
+    });
+}
+
+#[test]
+fn reissue_links_to_and_revokes_the_old_certification() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"title");
+
+        let second = next_id(1);
+        assert_noop!(
+            Template::reissue_certification(
+                RuntimeOrigin::signed(2),
+                first,
+                b"renewed".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::reissue_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            b"renewed".to_vec(),
+            b"description".to_vec()
+        ));
+        System::assert_last_event(
+            Event::CertificationReissued {
+                old_id: first,
+                new_id: second,
+            }
+            .into(),
+        );
+
+        let third = next_id(1);
+        assert_ok!(Template::reissue_certification(
+            RuntimeOrigin::signed(1),
+            second,
+            b"renewed again".to_vec(),
+            b"description".to_vec()
+        ));
+
+        let certification = |id| ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification(first).supersedes, None);
+        assert_eq!(certification(second).supersedes, Some(first));
+        assert_eq!(certification(third).supersedes, Some(second));
+        assert_eq!(certification(first).superseded_by, Some(second));
+        assert_eq!(certification(second).superseded_by, Some(third));
+        assert!(certification(first).revoked);
+        assert!(certification(second).revoked);
+        assert!(!certification(third).revoked);
+        assert_eq!(certification(third).title, b"renewed again".to_vec());
+
+        // A revoked certification cannot be reissued a second time.
+        assert_noop!(
+            Template::reissue_certification(
+                RuntimeOrigin::signed(1),
+                first,
+                b"renewed".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::CertificationRevoked
+        );
+    });
+}
+
+#[test]
+fn verify_certifications_batch_reports_each_item_in_order() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        let second = add_cert(2, b"second");
+        let first_hash = ListOfCertifications::<Test>::get(first)
+            .unwrap()
+            .content_hash;
+        let second_hash = ListOfCertifications::<Test>::get(second)
+            .unwrap()
+            .content_hash;
+        let missing = CertificationId(H256::repeat_byte(7));
+
+        let items = vec![
+            (first, first_hash),
+            (missing, first_hash),
+            (second, first_hash),
+            (second, second_hash),
+        ];
+        assert_ok!(Template::verify_certifications_batch(
+            RuntimeOrigin::signed(3),
+            items.try_into().unwrap()
+        ));
+        System::assert_last_event(
+            Event::BatchVerified {
+                results: vec![true, false, false, true].try_into().unwrap(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn title_and_description_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                vec![b'a'; 33],
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TitleTooLong
+        );
+        let id = add_cert(1, &[b'a'; 32]);
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"title".to_vec(),
+                vec![b'a'; 65],
+                0,
+                None
+            ),
+            Error::<Test>::DescriptionTooLong
+        );
+    });
+}
+
+#[test]
+fn titles_below_the_minimum_length_are_rejected() {
+    new_test_ext().execute_with(|| {
+        MinTitleLength::set(3);
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"ab".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TitleTooShort
+        );
+        let id = add_cert(1, b"abc");
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"ab".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::TitleTooShort
+        );
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"abcd".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+    });
+}
+
+#[test]
+fn all_storage_items_have_a_max_size() {
+    let storage_info = <Template as StorageInfoTrait>::storage_info();
+    assert!(!storage_info.is_empty());
+    for info in storage_info {
+        assert!(
+            info.max_size.is_some(),
+            "{} has no max size",
+            String::from_utf8_lossy(&info.storage_name)
+        );
+    }
+}
+
+#[test]
+fn stats_track_adds_and_removes() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Template::stats(), CertificationStats { total: 0, nonce: 0 });
+
+        let first = add_cert(1, b"first");
+        add_cert(2, b"second");
+        add_cert(1, b"third");
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            None
+        ));
+
+        // Removals lower the total but never the nonce.
+        assert_eq!(Template::stats(), CertificationStats { total: 2, nonce: 3 });
+        assert_eq!(
+            Template::stats().total,
+            ListOfCertifications::<Test>::iter().count() as u64
+        );
+    });
+}
+
+#[test]
+fn limits_match_the_configured_constants() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Template::limits(),
+            CertificationLimits {
+                max_certifications_per_owner: 10,
+                max_transfer_per_call: 5,
+                max_creates_per_window: 100,
+                max_total_certifications: 1_000,
+                max_meta_key_len: 16,
+                max_meta_value_len: 32,
+                max_metadata_entries: 2,
+                max_operators: 2,
+                max_offchain_scan: 10,
+                max_batch_size: 5,
+                max_title_length: 32,
+                min_title_length: 1,
+                max_description_length: 64,
+                max_expiry_checks_per_block: 10,
+                max_reason_len: 16,
+                max_chunk_len: 8,
+                max_chunks: 3,
+                max_owners_per_query: 3,
+                max_external_id_len: 32,
+                max_editors: 2,
+            }
+        );
+
+        // Limits are read at call time, so runtime-tunable ones are reflected.
+        MaxTitleLength::set(8);
+        assert_eq!(Template::limits().max_title_length, 8);
+    });
+}
+
+#[test]
+fn summary_of_returns_count_and_newest_first() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        System::set_block_number(2);
+        let second = add_cert(1, b"second");
+        System::set_block_number(3);
+        let third = add_cert(1, b"third");
+        System::set_block_number(4);
+        let fourth = add_cert(1, b"fourth");
+        add_cert(2, b"other owner");
+
+        assert_eq!(
+            Template::summary_of(&1, 3),
+            (4, vec![fourth, third, second])
+        );
+        assert_eq!(
+            Template::summary_of(&1, 10),
+            (4, vec![fourth, third, second, first])
+        );
+        assert_eq!(Template::summary_of(&1, 0), (4, vec![]));
+        assert_eq!(Template::summary_of(&3, 5), (0, vec![]));
+    });
+}
+
+#[test]
+fn summary_of_orders_same_block_certifications_like_certifications_of() {
+    new_test_ext().execute_with(|| {
+        for title in [b"first", b"other", b"third"] {
+            add_cert(1, title);
+        }
+
+        let mut newest = Template::certifications_of(&1);
+        newest.reverse();
+        assert_eq!(Template::summary_of(&1, 10), (3, newest.clone()));
+        assert_eq!(Template::summary_of(&1, 2), (3, newest[..2].to_vec()));
+    });
+}
+
+#[test]
+fn owner_index_keeps_insertion_order_by_default() {
+    new_test_ext().execute_with(|| {
+        let ids: Vec<_> = (0..4).map(|_| add_cert(1, b"title")).collect();
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), ids);
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            ids[1],
+            None
+        ));
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).into_inner(),
+            vec![ids[0], ids[2], ids[3]]
+        );
+    });
+}
+
+#[test]
+fn owner_index_can_be_kept_sorted() {
+    new_test_ext().execute_with(|| {
+        SortOwnerIndex::set(true);
+        let mut ids: Vec<_> = (0..4).map(|_| add_cert(1, b"title")).collect();
+        ids.sort();
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), ids);
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            ids[1],
+            None
+        ));
+        ids.remove(1);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), ids);
+
+        // Transfers keep both the sender's and the recipient's entries sorted.
+        let mut other: Vec<_> = (0..2).map(|_| add_cert(2, b"title")).collect();
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(1),
+            ids[0],
+            2
+        ));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), ids[0]));
+        other.push(ids.remove(0));
+        other.sort();
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), ids);
+        assert_eq!(CertificationsByOwner::<Test>::get(2).into_inner(), other);
+    });
+}
+
+#[test]
+fn renew_extends_expiry() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 5);
+
+        System::set_block_number(3);
+        assert_ok!(Template::renew_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            15
+        ));
+        System::assert_last_event(
+            Event::CertificationRenewed {
+                certification_id: id,
+                new_valid_until: 15,
+            }
+            .into(),
+        );
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.expires_at, Some(15));
+        assert_eq!(certification.updated_at, 3);
+    });
+}
+
+#[test]
+fn reclaim_revives_within_the_grace_period() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 5);
+
+        System::set_block_number(4);
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), id),
+            Error::<Test>::NotExpired
+        );
+
+        // Expired at 5, reclaimable until 10, valid for another 4 blocks.
+        System::set_block_number(10);
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Template::reclaim_certification(
+            RuntimeOrigin::signed(1),
+            id
+        ));
+        System::assert_last_event(
+            Event::CertificationReclaimed {
+                certification_id: id,
+                new_valid_until: 14,
+            }
+            .into(),
+        );
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification.expires_at, Some(14));
+        assert_eq!(certification.updated_at, 10);
+    });
+}
+
+#[test]
+fn reclaim_rejects_frozen_and_revoked() {
+    new_test_ext().execute_with(|| {
+        let frozen = add_expiring_cert(1, 5);
+        assert_ok!(Template::freeze_certification(
+            RuntimeOrigin::root(),
+            frozen
+        ));
+        let revoked = add_expiring_cert(1, 5);
+        assert_ok!(Template::revoke_certification(
+            RuntimeOrigin::signed(1),
+            revoked,
+            None
+        ));
+
+        System::set_block_number(6);
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), frozen),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), revoked),
+            Error::<Test>::CertificationRevoked
+        );
+    });
+}
+
+#[test]
+fn reclaim_rejects_an_empty_validity_period() {
+    new_test_ext().execute_with(|| {
+        ResetCreatedOnUpdate::set(true);
+        let id = add_expiring_cert(1, 5);
+
+        // Updating at the expiry block moves the creation up to it.
+        System::set_block_number(5);
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"edited".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_eq!(ListOfCertifications::<Test>::get(id).unwrap().created_at, 5);
+
+        System::set_block_number(6);
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), id),
+            Error::<Test>::NotRenewable
+        );
+    });
+}
+
+#[test]
+fn reclaim_fails_after_the_grace_period() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 5);
+        let permanent = add_cert(1, b"permanent");
+
+        System::set_block_number(11);
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), id),
+            Error::<Test>::ReclaimWindowClosed
+        );
+        assert_noop!(
+            Template::reclaim_certification(RuntimeOrigin::signed(1), permanent),
+            Error::<Test>::NotRenewable
+        );
+    });
+}
+
+#[test]
+fn renew_rejects_non_expiring_and_non_owned() {
+    new_test_ext().execute_with(|| {
+        let permanent = add_cert(1, b"title");
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), permanent, 20),
+            Error::<Test>::NotRenewable
+        );
+
+        let expiring = add_expiring_cert(1, 5);
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(2), expiring, 20),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn revoked_certifications_cannot_be_updated() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::revoke_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"rewritten".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::CertificationRevoked
+        );
+    });
+}
+
+#[test]
+fn renew_rejects_frozen_and_revoked() {
+    new_test_ext().execute_with(|| {
+        let frozen = add_expiring_cert(1, 10);
+        assert_ok!(Template::freeze_certification(
+            RuntimeOrigin::root(),
+            frozen
+        ));
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), frozen, 20),
+            Error::<Test>::CertificationFrozen
+        );
+
+        let revoked = add_expiring_cert(1, 10);
+        assert_ok!(Template::revoke_certification(
+            RuntimeOrigin::signed(1),
+            revoked,
+            None
+        ));
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), revoked, 20),
+            Error::<Test>::CertificationRevoked
+        );
+    });
+}
+
+#[test]
+fn renew_must_extend_into_the_future() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 10);
+
+        // Shortening or keeping the expiry is rejected.
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), id, 8),
+            Error::<Test>::RenewalNotExtending
+        );
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), id, 10),
+            Error::<Test>::RenewalNotExtending
+        );
+
+        // Once expired, a renewal must still end in the future.
+        System::set_block_number(20);
+        assert_noop!(
+            Template::renew_certification(RuntimeOrigin::signed(1), id, 15),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(Template::renew_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            30
+        ));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().expires_at,
+            Some(30)
+        );
+    });
+}
+
+#[test]
+fn deposits_are_priced_per_kind() {
+    new_test_ext().execute_with(|| {
+        let professional = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"professional".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Professional,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 10);
+        let academic = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"academic".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Academic,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 15);
+        assert_eq!(
+            CertificationDeposits::<Test>::get(professional),
+            Some((1, 10))
+        );
+        assert_eq!(CertificationDeposits::<Test>::get(academic), Some((1, 5)));
+
+        // Kinds without a deposit reserve nothing.
+        add_cert(1, b"other");
+        assert_eq!(Balances::reserved_balance(1), 15);
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            professional,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 5);
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            academic,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert!(CertificationDeposits::<Test>::get(professional).is_none());
+    });
+}
+
+#[test]
+fn deposit_is_refunded_to_the_original_depositor_after_transfer() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"professional".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Professional,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            id,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn add_certification_fails_without_funds_for_the_deposit() {
+    new_test_ext().execute_with(|| {
+        assert!(Template::add_certification(
+            RuntimeOrigin::signed(3),
+            b"professional".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Professional,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err());
+        assert_eq!(CertificationCount::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn owns_all_and_owns_any() {
+    new_test_ext().execute_with(|| {
+        let a = add_cert(1, b"a");
+        let b = add_cert(1, b"b");
+        let other = add_cert(2, b"c");
+        let unknown = CertificationId(H256::repeat_byte(9));
+
+        // Full ownership.
+        assert!(Template::owns_all(&1, &[a, b]));
+        assert!(Template::owns_any(&1, &[a, b]));
+
+        // Partial ownership.
+        assert!(!Template::owns_all(&1, &[a, other]));
+        assert!(Template::owns_any(&1, &[other, a]));
+        assert!(!Template::owns_any(&1, &[other]));
+
+        // An unknown id is never owned.
+        assert!(!Template::owns_all(&1, &[a, unknown]));
+        assert!(Template::owns_any(&1, &[unknown, b]));
+        assert!(!Template::owns_any(&1, &[unknown]));
+
+        assert!(Template::owns_all(&1, &[]));
+        assert!(!Template::owns_any(&1, &[]));
+    });
+}
+
+#[test]
+fn update_shrinks_a_title_stored_under_a_higher_limit() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"a title of twenty-two");
+        MaxTitleLength::set(8);
+
+        // The over-long certification is still readable.
+        assert_eq!(Template::owner_of(&id), Some(1));
+
+        // Only the new value is checked against the lowered limit.
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"still too long".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::TitleTooLong
+        );
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"short".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id)
+                .unwrap()
+                .title
+                .into_inner(),
+            b"short".to_vec()
+        );
+        MaxTitleLength::set(32);
+    });
+}
+
+fn add_superseding(
+    who: u64,
+    supersedes: Option<CertificationIdOf<Test>>,
+) -> DispatchResultWithPostInfo {
+    Template::add_certification(
+        RuntimeOrigin::signed(who),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        ContentType::PlainText,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        None,
+        None,
+        supersedes,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn add_certification_can_supersede_a_chain() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        let second = next_id(1);
+        assert_ok!(add_superseding(1, Some(first)));
+        System::assert_last_event(
+            Event::CertificationSuperseded {
+                old: first,
+                new: second,
+            }
+            .into(),
+        );
+        let third = next_id(1);
+        assert_ok!(add_superseding(1, Some(second)));
+
+        let certification = |id| ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(certification(first).supersedes, None);
+        assert_eq!(certification(first).superseded_by, Some(second));
+        assert_eq!(certification(second).supersedes, Some(first));
+        assert_eq!(certification(second).superseded_by, Some(third));
+        assert_eq!(certification(third).supersedes, Some(second));
+        assert_eq!(certification(third).superseded_by, None);
+    });
+}
+
+#[test]
+fn add_certification_rejects_invalid_supersede_target() {
+    new_test_ext().execute_with(|| {
+        let others = add_cert(2, b"theirs");
+        assert_noop!(
+            add_superseding(1, Some(others)),
+            Error::<Test>::InvalidSupersedeTarget
+        );
+        assert_noop!(
+            add_superseding(1, Some(CertificationId(H256::repeat_byte(9)))),
+            Error::<Test>::InvalidSupersedeTarget
+        );
+        assert_eq!(
+            ListOfCertifications::<Test>::get(others)
+                .unwrap()
+                .superseded_by,
+            None
+        );
+    });
+}
+
+#[test]
+fn duplicate_titles_are_allowed_by_default() {
+    new_test_ext().execute_with(|| {
+        add_cert(1, b"title");
+        add_cert(1, b"title");
+        assert_eq!(TitleIndex::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn duplicate_titles_are_rejected_when_enforced() {
+    new_test_ext().execute_with(|| {
+        EnforceUniqueTitlePerOwner::set(true);
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"title".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::DuplicateTitleForOwner
+        );
+
+        // Another owner may use the same title.
+        let theirs = add_cert(2, b"title");
+
+        // Nor can a transfer give an owner a second certification with the same title.
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(2),
+            theirs,
+            1
+        ));
+        assert_noop!(
+            Template::accept_transfer(RuntimeOrigin::signed(1), theirs),
+            Error::<Test>::DuplicateTitleForOwner
+        );
+
+        // Renaming or removing the certification frees the title.
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"renamed".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(1), theirs));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            theirs,
+            None
+        ));
+        add_cert(1, b"title");
+        EnforceUniqueTitlePerOwner::set(false);
+    });
+}
+
+#[test]
+fn ensure_owner_returns_owned_certifications() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert!(
+            Template::ensure_owner(&id, &1).unwrap()
+                == ListOfCertifications::<Test>::get(id).unwrap()
+        );
+        assert_eq!(
+            Template::ensure_owner(&id, &2).err(),
+            Some(Error::<Test>::NotOwner.into())
+        );
+        assert_eq!(
+            Template::ensure_owner(&CertificationId(H256::repeat_byte(9)), &1).err(),
+            Some(Error::<Test>::CertificationNotFound.into())
+        );
+    });
+}
+
+fn revoke(who: u64, id: CertificationIdOf<Test>) -> CertificationIdOf<Test> {
+    let new_id = next_id(who);
+    assert_ok!(Template::reissue_certification(
+        RuntimeOrigin::signed(who),
+        id,
+        b"reissued".to_vec(),
+        b"description".to_vec()
+    ));
+    new_id
+}
+
+#[test]
+fn on_idle_prunes_revoked_certifications_after_retention() {
+    new_test_ext().execute_with(|| {
+        let old = add_cert(1, b"title");
+        let new = revoke(1, old);
+        assert_eq!(RevokedAt::<Test>::get(old), Some(1));
+
+        // Still within retention.
+        Template::on_idle(6, Weight::MAX);
+        assert!(ListOfCertifications::<Test>::contains_key(old));
+
+        System::set_block_number(7);
+        Template::on_idle(7, Weight::MAX);
+        assert!(!ListOfCertifications::<Test>::contains_key(old));
+        assert!(!RevokedAt::<Test>::contains_key(old));
+        assert!(ListOfCertifications::<Test>::contains_key(new));
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).into_inner(),
+            vec![new]
+        );
+        System::assert_last_event(
+            Event::CertificationPruned {
+                certification_id: old,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn on_idle_respects_the_weight_budget() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"first");
+        revoke(1, first);
+        let second = add_cert(2, b"second");
+        revoke(2, second);
+
+        assert_eq!(Template::on_idle(10, Weight::zero()), Weight::zero());
+        assert!(ListOfCertifications::<Test>::contains_key(first));
+
+        // Room for the cursor, two reads and a single prune.
+        let db = <Test as frame_system::Config>::DbWeight::get();
+        let prune =
+            Template::remove_weight(<Test as crate::Config>::MaxCertificationsPerOwner::get());
+        let budget = db.reads_writes(1, 1) + db.reads(2) + prune;
+        assert!(Template::on_idle(10, budget).all_lte(budget));
+        assert_eq!(RevokedAt::<Test>::iter().count(), 1);
+        assert!(PruneCursor::<Test>::get().is_some());
+
+        // The next block resumes after the pruned entry.
+        Template::on_idle(10, db.reads_writes(1, 1) + db.reads(1) + prune);
+        assert_eq!(RevokedAt::<Test>::iter().count(), 0);
+        assert_eq!(PruneCursor::<Test>::get(), None);
+        assert!(!ListOfCertifications::<Test>::contains_key(first));
+        assert!(!ListOfCertifications::<Test>::contains_key(second));
+    });
+}
+
+#[test]
+fn kind_index_is_rebuilt_across_blocks_after_the_upgrade() {
+    new_test_ext().execute_with(|| {
+        let other = add_cert(1, b"other");
+        let academic = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"degree".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Academic,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        let legacy = add_cert(2, b"legacy");
+        frame::deps::sp_io::storage::set(
+            &ListOfCertifications::<Test>::hashed_key_for(legacy),
+            &[1, 2, 3],
+        );
+        assert_eq!(
+            Template::certifications_of_kind(CertificationKind::Academic),
+            vec![academic]
+        );
+
+        // Lose the index, as if certifications had been stored without it.
+        let _ = CertificationsByKind::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(1).put::<Template>();
+
+        RebuildKindIndex::<Test>::on_runtime_upgrade();
+        // The layout is unchanged, and so is the storage version.
+        assert_eq!(Template::on_chain_storage_version(), 1);
+        assert_eq!(KindIndexCursor::<Test>::get(), Some(None));
+
+        // Room for the cursor update and two certifications.
+        let db = <Test as frame_system::Config>::DbWeight::get();
+        Template::on_idle(2, db.reads_writes(3, 3));
+        assert_eq!(CertificationsByKind::<Test>::iter().count(), 2);
+        let cursor = KindIndexCursor::<Test>::get();
+        assert!(matches!(cursor, Some(Some(_))));
+
+        // Running the upgrade again while the rebuild is pending does not restart it.
+        RebuildKindIndex::<Test>::on_runtime_upgrade();
+        assert_eq!(KindIndexCursor::<Test>::get(), cursor);
+
+        Template::on_idle(3, Weight::MAX);
+        assert_eq!(KindIndexCursor::<Test>::get(), None);
+        assert_eq!(
+            Template::certifications_of_kind(CertificationKind::Academic),
+            vec![academic]
+        );
+        let mut others = Template::certifications_of_kind(CertificationKind::Other);
+        others.sort();
+        let mut expected = vec![other, legacy];
+        expected.sort();
+        assert_eq!(others, expected);
+    });
+}
+
+#[test]
+fn migrate_to_v1_translates_the_first_layout() {
+    new_test_ext().execute_with(|| {
+        let put_old = |byte: u8, owner: u64, title: &[u8]| {
+            let id = CertificationId(H256::repeat_byte(byte));
+            let old = OldCertification {
+                id: H256::repeat_byte(byte),
+                owner_id: owner,
+                title: title.to_vec(),
+                description: b"description".to_vec(),
+                created_at: 1u64,
+                updated_at: 2u64,
+            };
+            frame::deps::sp_io::storage::set(
+                &ListOfCertifications::<Test>::hashed_key_for(id),
+                &old.encode(),
+            );
+            id
+        };
+        let first = put_old(1, 1, b"title");
+        let second = put_old(2, 1, b"other");
+        let third = put_old(3, 2, b"title");
+        assert_eq!(Template::on_chain_storage_version(), 0);
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Template::on_chain_storage_version(), 1);
+
+        let migrated = ListOfCertifications::<Test>::get(first).unwrap();
+        assert_eq!(migrated.owner_id, 1);
+        assert_eq!(migrated.issuer, 1);
+        assert_eq!(migrated.title.to_vec(), b"title".to_vec());
+        assert_eq!(migrated.description.to_vec(), b"description".to_vec());
+        assert_eq!(
+            migrated.content_hash,
+            Template::content_hash_of(b"title", b"description")
+        );
+        assert_eq!((migrated.created_at, migrated.updated_at), (1, 2));
+        assert_eq!(migrated.version, 0);
+        assert_eq!(migrated.kind, CertificationKind::Other);
+        assert!(migrated.transferable && !migrated.revoked);
+
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+        assert_eq!(Template::count_of(&1), 2);
+        assert_eq!(Template::certifications_of(&2), vec![third]);
+        let mut by_kind = Template::certifications_of_kind(CertificationKind::Other);
+        by_kind.sort();
+        let mut expected = vec![first, second, third];
+        expected.sort();
+        assert_eq!(by_kind, expected);
+        assert_eq!(SelfIssuedCount::<Test>::get(1), 2);
+        assert_ok!(Template::do_try_state());
+
+        // The migrated certifications can be used like any other.
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            first,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+
+        // A second run leaves the migrated entries alone.
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+        assert_eq!(ListOfCertifications::<Test>::get(first).unwrap().version, 1);
+    });
+}
+
+#[test]
+fn force_origin_can_reassign_owner() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 3));
+        assert_ok!(Template::freeze_certification(RuntimeOrigin::root(), id));
+
+        assert_ok!(Template::reassign_owner(RuntimeOrigin::root(), id, 2));
+        System::assert_last_event(
+            Event::CertificationOwnerReassigned {
+                certification_id: id,
+                old_owner: 1,
+                new_owner: 2,
+            }
+            .into(),
+        );
+        assert_eq!(Template::owner_of(&id), Some(2));
+        assert!(CertificationsByOwner::<Test>::get(1).is_empty());
+        assert_eq!(CertificationsByOwner::<Test>::get(2).into_inner(), vec![id]);
+        assert!(!PendingTransfers::<Test>::contains_key(id));
+    });
+}
+
+#[test]
+fn reassigning_ends_the_escrow_of_the_previous_owner() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::transfer_and_freeze(
+            RuntimeOrigin::signed(1),
+            id,
+            2
+        ));
+        assert!(EscrowFrozen::<Test>::contains_key(id));
+
+        assert_ok!(Template::reassign_owner(RuntimeOrigin::root(), id, 3));
+        assert!(!EscrowFrozen::<Test>::contains_key(id));
+        assert!(ListOfCertifications::<Test>::get(id).unwrap().frozen);
+        assert_noop!(
+            Template::unfreeze_certification(RuntimeOrigin::signed(3), id),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::unfreeze_certification(RuntimeOrigin::root(), id));
+    });
+}
+
+#[test]
+fn reassign_owner_requires_force_origin() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::reassign_owner(RuntimeOrigin::signed(1), id, 2),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Template::reassign_owner(
+                RuntimeOrigin::root(),
+                CertificationId(H256::repeat_byte(9)),
+                2
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+    });
+}
+
+#[test]
+fn reassigning_to_the_current_owner_keeps_the_title_index() {
+    new_test_ext().execute_with(|| {
+        EnforceUniqueTitlePerOwner::set(true);
+        let id = add_cert(1, b"title");
+
+        assert_ok!(Template::reassign_owner(RuntimeOrigin::root(), id, 1));
+        assert_eq!(Template::owner_of(&id), Some(1));
+        assert_eq!(TitleIndex::<Test>::iter_prefix(1).count(), 1);
+        assert_eq!(Template::titles_sorted(&1), vec![(b"title".to_vec(), id.0)]);
+    });
+}
+
+fn add_valid_for(expires_at: Option<u64>, valid_for: Option<u64>) -> DispatchResultWithPostInfo {
+    Template::add_certification(
+        RuntimeOrigin::signed(1),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        ContentType::PlainText,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        expires_at,
+        valid_for,
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn expiry_can_be_given_as_a_duration() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(20);
+        let id = next_id(1);
+        assert_ok!(add_valid_for(None, Some(100)));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().expires_at,
+            Some(120)
+        );
+
+        assert_noop!(
+            add_valid_for(Some(50), Some(100)),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_noop!(add_valid_for(None, Some(0)), Error::<Test>::InvalidExpiry);
+    });
+}
+
+#[test]
+fn expiry_duration_overflow_is_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(u64::MAX - 5);
+        assert_noop!(add_valid_for(None, Some(10)), Error::<Test>::ExpiryOverflow);
+    });
+}
+
+fn expiry_warnings() -> Vec<CertificationIdOf<Test>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::Template(Event::CertificationExpiringSoon {
+                certification_id, ..
+            }) => Some(certification_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn expiry_warning_is_emitted_once() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 20);
+
+        // Not yet within the warning window.
+        Template::on_initialize(9);
+        assert!(expiry_warnings().is_empty());
+
+        Template::on_initialize(10);
+        assert_eq!(expiry_warnings(), vec![id]);
+        assert!(ListOfCertifications::<Test>::get(id).unwrap().warned);
+
+        Template::on_initialize(11);
+        Template::on_initialize(12);
+        assert_eq!(expiry_warnings(), vec![id]);
+    });
+}
+
+#[test]
+fn renewal_resets_the_expiry_warning() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 20);
+        Template::on_initialize(10);
+        assert_eq!(expiry_warnings(), vec![id]);
+
+        assert_ok!(Template::renew_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            120
+        ));
+        assert!(!ListOfCertifications::<Test>::get(id).unwrap().warned);
+
+        Template::on_initialize(11);
+        assert_eq!(expiry_warnings(), vec![id]);
+        Template::on_initialize(110);
+        assert_eq!(expiry_warnings(), vec![id, id]);
+    });
+}
+
+#[test]
+fn expiry_checks_are_bounded_per_block() {
+    new_test_ext().execute_with(|| {
+        MaxExpiryChecksPerBlock::set(1);
+        let mut ids = vec![add_expiring_cert(1, 20), add_expiring_cert(1, 20)];
+
+        Template::on_initialize(10);
+        assert_eq!(expiry_warnings().len(), 1);
+        assert!(ExpiryCheckCursor::<Test>::get().is_some());
+
+        Template::on_initialize(11);
+        let mut warned = expiry_warnings();
+        warned.sort();
+        ids.sort();
+        assert_eq!(warned, ids);
+        MaxExpiryChecksPerBlock::set(10);
+    });
+}
+
+#[test]
+fn force_remove_certification_works() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::freeze_certification(RuntimeOrigin::root(), id));
+
+        assert_ok!(Template::force_remove_certification(
+            RuntimeOrigin::root(),
+            id,
+            None
+        ));
+        System::assert_last_event(
+            Event::CertificationForceRemoved {
+                certification_id: id,
+                owner: 1,
+                reason: None,
+            }
+            .into(),
+        );
+        assert!(!Template::exists(&id));
+        assert!(CertificationsByOwner::<Test>::get(1).is_empty());
+        assert_noop!(
+            Template::force_remove_certification(RuntimeOrigin::root(), id, None),
+            Error::<Test>::CertificationNotFound
+        );
+    });
+}
+
+#[test]
+fn force_origin_is_configurable() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+
+        // The mock also accepts a signed admin account as force origin.
+        assert_noop!(
+            Template::force_remove_certification(RuntimeOrigin::signed(1), id, None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Template::reassign_owner(RuntimeOrigin::signed(2), id, 2),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::reassign_owner(
+            RuntimeOrigin::signed(Admin::get()),
+            id,
+            2
+        ));
+        assert_ok!(Template::force_remove_certification(
+            RuntimeOrigin::signed(Admin::get()),
+            id,
+            None
+        ));
+        assert!(!Template::exists(&id));
+    });
+}
+
+#[test]
+fn certification_helper_returns_none_for_undecodable_entries() {
+    new_test_ext().execute_with(|| {
+        let valid = add_cert(1, b"valid");
+        let legacy = add_cert(1, b"legacy");
+        // An entry of an older, shorter layout.
+        frame::deps::sp_io::storage::set(
+            &ListOfCertifications::<Test>::hashed_key_for(legacy),
+            &(legacy, 1u64).encode(),
+        );
+
+        assert!(Template::certification(&valid).is_some());
+        assert!(Template::certification(&legacy).is_none());
+        assert!(Template::certification(&CertificationId(H256::zero())).is_none());
+        assert_eq!(
+            Template::get_certification_or_err(&legacy).map(|_| ()),
+            Err(Error::<Test>::CorruptedEntry.into())
+        );
+    });
+}
+
+#[test]
+fn try_get_certification_tells_missing_from_undecodable() {
+    new_test_ext().execute_with(|| {
+        let valid = add_cert(1, b"valid");
+        let malformed = add_cert(1, b"malformed");
+        frame::deps::sp_io::storage::set(
+            &ListOfCertifications::<Test>::hashed_key_for(malformed),
+            &[0xff; 3],
+        );
+
+        assert_eq!(
+            Template::try_get_certification(&valid).map(|certification| certification.id),
+            Ok(valid)
+        );
+        assert_eq!(
+            Template::try_get_certification(&malformed).map(|_| ()),
+            Err(CertificationReadError::DecodeFailed)
+        );
+        assert_eq!(
+            Template::try_get_certification(&CertificationId(H256::zero())).map(|_| ()),
+            Err(CertificationReadError::NotFound)
+        );
+    });
+}
+
+#[test]
+fn iter_valid_skips_corrupt_entries() {
+    new_test_ext().execute_with(|| {
+        let mut valid = vec![add_cert(1, b"first"), add_cert(1, b"second")];
+        let corrupt = add_cert(2, b"corrupt");
+        frame::deps::sp_io::storage::set(
+            &ListOfCertifications::<Test>::hashed_key_for(corrupt),
+            &[1, 2, 3],
+        );
+
+        let mut found: Vec<_> = Template::iter_valid().map(|(id, _)| id).collect();
+        found.sort();
+        valid.sort();
+        assert_eq!(found, valid);
+
+        // The skipped entries can be listed, and reading has no side effects.
+        let events = System::events().len();
+        assert_eq!(Template::corrupt_entries(), vec![corrupt]);
+        assert_eq!(Template::iter_valid().count(), 2);
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+#[test]
+fn certification_id_round_trips() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let hash: H256 = id.into();
+        assert_eq!(CertificationId::from(hash), id);
+        assert_eq!(id.into_inner(), hash);
+
+        // The id encodes like the wrapped hash, so existing keys and clients are unaffected.
+        assert_eq!(id.encode(), hash.encode());
+        assert_eq!(
+            CertificationIdOf::<Test>::decode(&mut &hash.encode()[..]).unwrap(),
+            id
+        );
+        assert!(ListOfCertifications::<Test>::hashed_key_for(id).ends_with(&hash.encode()));
+        assert_eq!(
+            ListOfCertifications::<Test>::get(CertificationId::from(hash))
+                .unwrap()
+                .id,
+            id
+        );
+    });
+}
+
+#[test]
+fn count_of_stays_in_sync_across_transfers() {
+    new_test_ext().execute_with(|| {
+        let ids = [add_cert(1, b"a"), add_cert(1, b"b"), add_cert(1, b"c")];
+        add_cert(2, b"d");
+        assert_eq!((Template::count_of(&1), Template::count_of(&2)), (3, 1));
+
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(1),
+            ids[0],
+            2
+        ));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), ids[0]));
+        assert_eq!((Template::count_of(&1), Template::count_of(&2)), (2, 2));
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            ids[1],
+            None
+        ));
+        assert_eq!((Template::count_of(&1), Template::count_of(&2)), (1, 2));
+
+        assert_ok!(Template::transfer_all_certifications(
+            RuntimeOrigin::signed(2),
+            1
+        ));
+        assert_eq!((Template::count_of(&1), Template::count_of(&2)), (3, 0));
+        assert!(!CertificationCountByOwner::<Test>::contains_key(2));
+        for who in [1, 2] {
+            assert_eq!(
+                Template::count_of(&who),
+                CertificationsByOwner::<Test>::get(who).len() as u32
+            );
+        }
+    });
+}
+
+#[test]
+fn has_any_certification_follows_the_owner_count() {
+    new_test_ext().execute_with(|| {
+        assert!(!Template::has_any_certification(&1));
+
+        let id = add_cert(1, b"title");
+        assert!(Template::has_any_certification(&1));
+        assert!(<Template as CertificationInspect<u64>>::has_any_certification(&1));
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert!(!Template::has_any_certification(&1));
+    });
+}
+
+#[test]
+fn lifecycle_events_are_indexed_by_owner_and_id() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+
+        let expected = vec![
+            <Test as frame_system::Config>::Hashing::hash_of(&1u64),
+            id.into_inner(),
+        ];
+        let indexed: Vec<_> = System::events()
+            .into_iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::Template(
+                        Event::CertificationStored { .. }
+                            | Event::CertificationUpdated { .. }
+                            | Event::CertificationRemoved { .. }
+                    )
+                )
+            })
+            .collect();
+        assert_eq!(indexed.len(), 3);
+        for record in indexed {
+            assert_eq!(record.topics, expected);
+        }
+        assert_eq!(frame_system::EventTopics::<Test>::get(expected[0]).len(), 3);
+    });
+}
+
+#[test]
+fn soulbound_certifications_cannot_be_transferred() {
+    new_test_ext().execute_with(|| {
+        let soulbound = next_id(1);
+        assert_ok!(Template::add_certification(
+            RuntimeOrigin::signed(1),
+            b"soulbound".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            false,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        assert!(
+            !ListOfCertifications::<Test>::get(soulbound)
+                .unwrap()
+                .transferable
+        );
+        let normal = add_cert(1, b"normal");
+        assert!(
+            ListOfCertifications::<Test>::get(normal)
+                .unwrap()
+                .transferable
+        );
+
+        assert_noop!(
+            Template::offer_transfer(RuntimeOrigin::signed(1), soulbound, 2),
+            Error::<Test>::NotTransferable
+        );
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::NotTransferable
+        );
+
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(1),
+            normal,
+            2
+        ));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), normal));
+        assert_eq!(Template::owner_of(&normal), Some(2));
+        assert_eq!(Template::owner_of(&soulbound), Some(1));
+    });
+}
+
+fn validate_quota(who: u64, call: crate::Call<Test>) -> Result<(), TransactionValidityError> {
+    CheckCertificationQuota::<Test>::new()
+        .validate(
+            RuntimeOrigin::signed(who),
+            &call.into(),
+            &DispatchInfo::default(),
+            0,
+            (),
+            &TxBaseImplication(()),
+            TransactionSource::External,
+        )
+        .map(|_| ())
+}
+
+#[test]
+fn quota_extension_counts_batches_and_upsert_inserts() {
+    new_test_ext().execute_with(|| {
+        let add_batch = |items: &[&[u8]]| crate::Call::<Test>::add_certifications {
+            certifications: batch(items),
+        };
+        let upsert = |certification_id| crate::Call::<Test>::upsert_certification {
+            certification_id,
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+        };
+
+        let mut ids = Vec::new();
+        for _ in 0..8 {
+            ids.push(add_cert(1, b"title"));
+        }
+        let exceeded = TransactionValidityError::Invalid(InvalidTransaction::Custom(
+            crate::extensions::QUOTA_EXCEEDED,
+        ));
+
+        // Every item of a batch counts.
+        assert_ok!(validate_quota(1, add_batch(&[b"a", b"b"])));
+        assert_eq!(
+            validate_quota(1, add_batch(&[b"a", b"b", b"c"])),
+            Err(exceeded)
+        );
+
+        // An upsert only counts when it would create the certification.
+        add_cert(1, b"title");
+        add_cert(1, b"title");
+        assert_eq!(validate_quota(1, upsert(next_id(1))), Err(exceeded));
+        assert_ok!(validate_quota(1, upsert(ids[0])));
+    });
+}
+
+#[test]
+fn quota_extension_rejects_creations_over_the_owner_cap() {
+    new_test_ext().execute_with(|| {
+        let add = || crate::Call::<Test>::add_certification {
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            content_type: ContentType::PlainText,
+            kind: CertificationKind::Other,
+            transferable: true,
+            metadata: Default::default(),
+            expires_at: None,
+            valid_for: None,
+            supersedes: None,
+            request_id: None,
+            external_id: None,
+        };
+        let add_for = || crate::Call::<Test>::add_certification_for {
+            owner: 1,
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+        };
+
+        let mut ids = Vec::new();
+        for _ in 0..9 {
+            ids.push(add_cert(1, b"title"));
+        }
+        assert_ok!(validate_quota(1, add()));
+        assert_ok!(validate_quota(2, add_for()));
+
+        add_cert(1, b"title");
+        let exceeded = TransactionValidityError::Invalid(InvalidTransaction::Custom(
+            crate::extensions::QUOTA_EXCEEDED,
+        ));
+        assert_eq!(validate_quota(1, add()), Err(exceeded));
+        // The quota of the future owner applies, not the one of the operator.
+        assert_eq!(validate_quota(2, add_for()), Err(exceeded));
+        assert_ok!(validate_quota(2, add()));
+
+        // Other calls are not affected.
+        assert_ok!(validate_quota(
+            1,
+            crate::Call::<Test>::remove_certification {
+                certification_id: ids[0],
+                reason: None,
+            }
+        ));
+    });
+}
+
+#[test]
+fn view_mirrors_the_stored_certification() {
+    new_test_ext().execute_with(|| {
+        let id = add_expiring_cert(1, 50);
+        assert_ok!(Template::set_visibility(
+            RuntimeOrigin::signed(1),
+            id,
+            false
+        ));
+        let stored = ListOfCertifications::<Test>::get(id).unwrap();
+
+        let view = Template::view(&id).unwrap();
+        assert_eq!(view.id, id.into_inner());
+        assert_eq!(view.owner, stored.owner_id);
+        assert_eq!(view.issuer, stored.issuer);
+        assert_eq!(view.title, stored.title.to_vec());
+        assert_eq!(view.description, stored.description.to_vec());
+        assert_eq!(view.content_type, stored.content_type);
+        assert_eq!(view.content_hash, stored.content_hash);
+        assert_eq!(view.created_at, stored.created_at);
+        assert_eq!(view.updated_at, stored.updated_at);
+        assert_eq!(view.expires_at, Some(50));
+        assert_eq!(view.schema_version, stored.schema_version);
+        assert_eq!(view.version, stored.version);
+        assert!(!view.public);
+        assert_eq!(
+            (view.frozen, view.transferable, view.revoked),
+            (stored.frozen, stored.transferable, stored.revoked)
+        );
+
+        // Private content is only visible to the owner through `view_as`.
+        assert_eq!(Template::view_as(&id, Some(&1)), Some(view.clone()));
+        let redacted = Template::view_as(&id, Some(&2)).unwrap();
+        assert!(redacted.title.is_empty() && redacted.description.is_empty());
+        assert_eq!(redacted.owner, 1);
+
+        assert_eq!(Template::view(&CertificationId(H256::zero())), None);
+    });
+}
+
+#[test]
+fn pausing_blocks_mutations() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::set_paused(RuntimeOrigin::signed(1), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Template::set_paused(RuntimeOrigin::root(), true));
+        System::assert_last_event(Event::PalletPausedSet { paused: true }.into());
+
+        assert_noop!(
+            Template::add_certification(
+                RuntimeOrigin::signed(1),
+                b"other".to_vec(),
+                b"description".to_vec(),
+                ContentType::PlainText,
+                CertificationKind::Other,
+                true,
+                Default::default(),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"new title".to_vec(),
+                b"description".to_vec(),
+                0,
+                None
+            ),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Template::remove_certification(RuntimeOrigin::signed(1), id, None),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Template::offer_transfer(RuntimeOrigin::signed(1), id, 2),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Issuer::issue_for(RuntimeOrigin::signed(1), 1, b"t".to_vec(), b"d".to_vec()),
+            Error::<Test>::PalletPaused
+        );
+
+        // Reads are unaffected.
+        assert_eq!(Template::owner_of(&id), Some(1));
+        assert!(Template::view(&id).is_some());
+    });
+}
+
+#[test]
+fn unpausing_restores_mutations() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::set_paused(RuntimeOrigin::root(), true));
+        assert_ok!(Template::set_paused(
+            RuntimeOrigin::signed(Admin::get()),
+            false
+        ));
+        System::assert_last_event(Event::PalletPausedSet { paused: false }.into());
+
+        add_cert(1, b"other");
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            id,
+            None
+        ));
+    });
+}
+
+#[test]
+fn removal_reason_is_emitted() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            Some(bounded(b"duplicate"))
+        ));
+        System::assert_last_event(
+            Event::CertificationRemoved {
+                who: 1,
+                certification_id: id,
+                reason: Some(bounded(b"duplicate")),
+            }
+            .into(),
+        );
+
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::force_remove_certification(
+            RuntimeOrigin::root(),
+            id,
+            Some(bounded(b"court order"))
+        ));
+        System::assert_last_event(
+            Event::CertificationForceRemoved {
+                certification_id: id,
+                owner: 1,
+                reason: Some(bounded(b"court order")),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn over_long_removal_reason_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let max = <Test as crate::Config>::MaxReasonLen::get() as usize;
+        assert!(crate::ReasonOf::<Test>::try_from(vec![b'x'; max + 1]).is_err());
+
+        // A call carrying an over-long reason does not even decode.
+        let id = CertificationId(H256::repeat_byte(1));
+        let call = crate::Call::<Test>::remove_certification {
+            certification_id: id,
+            reason: Some(bounded(&vec![b'x'; max])),
+        };
+        assert!(crate::Call::<Test>::decode(&mut &call.encode()[..]).is_ok());
+        let mut encoded = call.encode();
+        encoded.truncate(1);
+        encoded.extend((id, Some(vec![b'x'; max + 1])).encode());
+        assert!(crate::Call::<Test>::decode(&mut &encoded[..]).is_err());
+    });
+}
+
+#[test]
+fn issuer_can_revoke_with_a_reason() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::revoke_certification(RuntimeOrigin::signed(2), id, None),
+            Error::<Test>::NotIssuer
+        );
+
+        System::set_block_number(2);
+        assert_ok!(Template::revoke_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            Some(bounded(b"issued in error"))
+        ));
+        System::assert_last_event(
+            Event::CertificationRevoked {
+                who: 1,
+                certification_id: id,
+                reason: Some(bounded(b"issued in error")),
+            }
+            .into(),
+        );
+
+        let certification = ListOfCertifications::<Test>::get(id).unwrap();
+        assert!(certification.revoked);
+        assert_eq!(
+            certification.revocation_reason,
+            Some(bounded(b"issued in error"))
+        );
+        assert_eq!(RevokedAt::<Test>::get(id), Some(2));
+
+        assert_noop!(
+            Template::revoke_certification(RuntimeOrigin::signed(1), id, None),
+            Error::<Test>::CertificationRevoked
+        );
+    });
+}
+
+#[test]
+fn history_records_changes_in_order() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        System::set_block_number(2);
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        System::set_block_number(3);
+        assert_ok!(Template::revoke_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+
+        assert_eq!(
+            Template::history(&id),
+            vec![
+                (1, HistoryAction::Created),
+                (2, HistoryAction::Updated),
+                (3, HistoryAction::Revoked)
+            ]
+        );
+
+        // The log is removed with the certification.
+        System::set_block_number(4);
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert!(Template::history(&id).is_empty());
+        assert_eq!(History::<Test>::iter_prefix(id).count(), 0);
+        assert!(!HistoryHead::<Test>::contains_key(id));
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn full_history_page_continues_on_the_next_and_drops_the_oldest() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        let max = <Test as crate::Config>::MaxHistoryEntries::get();
+        for version in 0..max - 1 {
+            assert_ok!(Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                version,
+                None
+            ));
+        }
+        assert_eq!(History::<Test>::get(id, 0).len(), max as usize);
+        assert_eq!(HistoryHead::<Test>::get(id), 0);
+
+        // A full page does not lock the certification.
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"title".to_vec(),
+            b"description".to_vec(),
+            max - 1,
+            None
+        ));
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_eq!(HistoryHead::<Test>::get(id), 1);
+        assert_eq!(History::<Test>::get(id, 1).len(), 2);
+
+        let history = Template::history(&id);
+        assert_eq!(history.len(), max as usize + 2);
+        assert_eq!(history[0], (1, HistoryAction::Created));
+        assert_eq!(history.last(), Some(&(1, HistoryAction::Transferred)));
+
+        // Filling the second page drops the first one.
+        for version in max..2 * max - 1 {
+            assert_ok!(Template::update_certification(
+                RuntimeOrigin::signed(2),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec(),
+                version,
+                None
+            ));
+        }
+        assert_eq!(HistoryHead::<Test>::get(id), 2);
+        assert!(!History::<Test>::contains_key(id, 0));
+        let history = Template::history(&id);
+        assert_eq!(history.len(), max as usize + 1);
+        assert_eq!(history[1], (1, HistoryAction::Transferred));
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_detects_desynced_indexes() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        add_cert(2, b"title");
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_ok!(Template::do_try_state());
+
+        // An owner index entry without a matching certification.
+        CertificationsByOwner::<Test>::mutate(2, |ids| ids.try_push(CertificationId(H256::zero())))
+            .unwrap();
+        CertificationCountByOwner::<Test>::mutate(2, |count| *count += 1);
+        assert_eq!(
+            Template::do_try_state(),
+            Err("owner index points to a missing or foreign certification".into())
+        );
+    });
+
+    new_test_ext().execute_with(|| {
+        // A certification missing from its owner's index.
+        let id = add_cert(1, b"title");
+        CertificationsByOwner::<Test>::remove(1);
+        CertificationCountByOwner::<Test>::remove(1);
+        assert_eq!(
+            Template::do_try_state(),
+            Err("certification missing from its owner's index".into())
+        );
+        assert!(Template::exists(&id));
+    });
+
+    new_test_ext().execute_with(|| {
+        add_cert(1, b"title");
+        CertificationCount::<Test>::put(2);
+        assert_eq!(
+            Template::do_try_state(),
+            Err("CertificationCount does not match the number of certifications".into())
+        );
+    });
+
+    new_test_ext().execute_with(|| {
+        // A per-owner count for an account without an owner index entry.
+        add_cert(1, b"title");
+        CertificationCountByOwner::<Test>::insert(3, 1);
+        assert_eq!(
+            Template::do_try_state(),
+            Err("CertificationCountByOwner does not match the owner index".into())
+        );
+    });
+
+    new_test_ext().execute_with(|| {
+        // An editor outliving its certification.
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::do_try_state());
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        Editors::<Test>::insert(id, 2, ());
+        assert_eq!(
+            Template::do_try_state(),
+            Err("per-certification entry left behind by a removal".into())
+        );
+    });
+}
+
+#[test]
+fn on_initialize_weight_scales_with_expirations() {
+    new_test_ext().execute_with(|| {
+        let empty = Template::on_initialize(1);
+        assert_eq!(empty, <() as WeightInfo>::process_expirations(0, 0));
+
+        // Scanning without emitting any warning is still paid for.
+        add_cert(1, b"title");
+        let idle = Template::on_initialize(2);
+        assert_eq!(idle, <() as WeightInfo>::process_expirations(1, 0));
+        assert!(idle.all_gt(empty));
+
+        add_expiring_cert(1, 20);
+        add_expiring_cert(1, 20);
+        let busy = Template::on_initialize(10);
+        assert_eq!(expiry_warnings().len(), 2);
+        assert_eq!(busy, <() as WeightInfo>::process_expirations(3, 2));
+        assert!(busy.all_gt(idle));
+    });
+}
+
+#[test]
+fn remove_weight_is_refunded_to_the_owner_index_size() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"first");
+        add_cert(1, b"second");
+        add_cert(1, b"third");
+
+        let post_info = Template::remove_certification(RuntimeOrigin::signed(1), id, None).unwrap();
+        assert_eq!(post_info.actual_weight, Some(Template::remove_weight(3)));
+        assert!(Template::remove_weight(10).any_gt(Template::remove_weight(3)));
+        assert!(Template::remove_weight(3).any_gt(<() as WeightInfo>::remove_certification(3)));
+
+        // A forced removal is charged the same.
+        let second = Template::certifications_of(&1)[0];
+        let post_info =
+            Template::force_remove_certification(RuntimeOrigin::root(), second, None).unwrap();
+        assert_eq!(post_info.actual_weight, Some(Template::remove_weight(2)));
+    });
+}
+
+#[test]
+fn certifications_can_be_found_by_title_prefix() {
+    new_test_ext().execute_with(|| {
+        let alpha = add_cert(1, b"Alpha");
+        let alphabet = add_cert(1, b"Alphabet");
+        add_cert(1, b"Beta");
+        add_cert(2, b"Alpha");
+
+        assert_eq!(
+            Template::certifications_with_title_prefix(&1, b"Alpha"),
+            vec![alpha, alphabet]
+        );
+        assert_eq!(Template::certifications_with_title_prefix(&1, b"").len(), 3);
+        assert!(Template::certifications_with_title_prefix(&1, b"alpha").is_empty());
+        assert!(Template::certifications_with_title_prefix(&3, b"Alpha").is_empty());
+    });
+}
+
+#[test]
+fn titles_are_listed_alphabetically() {
+    new_test_ext().execute_with(|| {
+        let charlie = add_cert(1, b"Charlie");
+        let alpha = add_cert(1, b"Alpha");
+        let bravo = add_cert(1, b"Bravo");
+        // Titles sharing the sort key prefix are still ordered by the full title.
+        let long_b = add_cert(1, b"Certificate of Merit B");
+        let long_a = add_cert(1, b"Certificate of Merit A");
+        add_cert(2, b"Aardvark");
+
+        let titles = |owner| {
+            Template::titles_sorted(&owner)
+                .into_iter()
+                .map(|(title, id)| (title, CertificationId(id)))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            titles(1),
+            vec![
+                (b"Alpha".to_vec(), alpha),
+                (b"Bravo".to_vec(), bravo),
+                (b"Certificate of Merit A".to_vec(), long_a),
+                (b"Certificate of Merit B".to_vec(), long_b),
+                (b"Charlie".to_vec(), charlie),
+            ]
+        );
+
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            alpha,
+            b"Delta".to_vec(),
+            b"description".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            long_b,
+            None
+        ));
+        assert_eq!(
+            titles(1),
+            vec![
+                (b"Bravo".to_vec(), bravo),
+                (b"Certificate of Merit A".to_vec(), long_a),
+                (b"Charlie".to_vec(), charlie),
+                (b"Delta".to_vec(), alpha),
+            ]
+        );
+        assert_eq!(TitlesByOwner::<Test>::iter_prefix(1).count(), 4);
+        assert!(titles(3).is_empty());
+    });
+}
+
+#[test]
+fn owner_index_changes_emit_events_if_enabled() {
+    new_test_ext().execute_with(|| {
+        let index_events = || {
+            System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Template(
+                        event @ (Event::OwnerIndexAdded { .. } | Event::OwnerIndexRemoved { .. }),
+                    ) => Some(event),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        add_cert(1, b"quiet");
+        assert!(index_events().is_empty());
+
+        EmitIndexEvents::set(true);
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::offer_transfer(RuntimeOrigin::signed(1), id, 2));
+        assert_ok!(Template::accept_transfer(RuntimeOrigin::signed(2), id));
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(2),
+            id,
+            None
+        ));
+        assert_eq!(
+            index_events(),
+            vec![
+                Event::OwnerIndexAdded {
+                    owner: 1,
+                    certification_id: id
+                },
+                Event::OwnerIndexRemoved {
+                    owner: 1,
+                    certification_id: id
+                },
+                Event::OwnerIndexAdded {
+                    owner: 2,
+                    certification_id: id
+                },
+                Event::OwnerIndexRemoved {
+                    owner: 2,
+                    certification_id: id
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn headers_match_the_full_certifications() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"First");
+        System::set_block_number(2);
+        let second = add_cert(1, b"Second");
+        assert_ok!(Template::set_visibility(
+            RuntimeOrigin::signed(1),
+            second,
+            false
+        ));
+        add_cert(2, b"Other");
+
+        let headers = Template::certification_headers_of(&1);
+        assert_eq!(headers.len(), 2);
+        for (header, id) in headers.iter().zip([first, second]) {
+            let view = Template::view_as(&id, None).unwrap();
+            assert_eq!(header.id, view.id);
+            assert_eq!(header.owner, view.owner);
+            assert_eq!(header.title, view.title);
+            assert_eq!(header.created_at, view.created_at);
+        }
+        assert_eq!(headers[0].title, b"First".to_vec());
+        assert!(headers[1].title.is_empty());
+        assert_eq!(headers[1].created_at, 2);
+        assert!(Template::certification_headers_of(&3).is_empty());
+    });
+}
+
+#[test]
+fn certifications_of_many_groups_certifications_by_owner() {
+    new_test_ext().execute_with(|| {
+        let first = add_cert(1, b"First");
+        System::set_block_number(2);
+        let second = add_cert(1, b"Second");
+        let other = add_cert(2, b"Other");
+        assert_ok!(Template::set_visibility(
+            RuntimeOrigin::signed(1),
+            second,
+            false
+        ));
+
+        let result = Template::certifications_of_many(vec![1, 3, 2]);
+        let ids: Vec<_> = result
+            .iter()
+            .map(|(owner, views)| (*owner, views.iter().map(|view| view.id).collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (1, vec![first.into_inner(), second.into_inner()]),
+                (3, vec![]),
+                (2, vec![other.into_inner()]),
+            ]
+        );
+        // Private content is not revealed.
+        assert_eq!(result[0].1[0].title, b"First".to_vec());
+        assert!(result[0].1[1].title.is_empty());
+
+        assert!(Template::certifications_of_many(vec![]).is_empty());
+        // Owners beyond `MaxOwnersPerQuery` are not looked up.
+        assert_eq!(Template::certifications_of_many(vec![1, 2, 3, 4]).len(), 3);
+    });
+}
+
+#[test]
+fn certifications_of_is_ordered_by_creation_then_id() {
+    new_test_ext().execute_with(|| {
+        let received = add_cert(2, b"Received");
+        let bystander = add_cert(2, b"Bystander");
+        System::set_block_number(2);
+        let a = add_cert(1, b"A");
+        let b = add_cert(1, b"B");
+        let mut same_block = vec![a, b];
+        same_block.sort();
+        let oldest = Template::certifications_of(&1);
+        assert_eq!(oldest, same_block);
+
+        // A transfer between other accounts leaves the order untouched.
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(2),
+            bystander,
+            3
+        ));
+        assert_ok!(Template::accept_transfer(
+            RuntimeOrigin::signed(3),
+            bystander
+        ));
+        assert_eq!(Template::certifications_of(&1), oldest);
+
+        // A certification received later still sorts by when it was created.
+        System::set_block_number(3);
+        assert_ok!(Template::offer_transfer(
+            RuntimeOrigin::signed(2),
+            received,
+            1
+        ));
+        assert_ok!(Template::accept_transfer(
+            RuntimeOrigin::signed(1),
+            received
+        ));
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).last(),
+            Some(&received)
+        );
+        assert_eq!(
+            Template::certifications_of(&1),
+            [vec![received], same_block].concat()
+        );
+    });
+}
+
+#[test]
+fn clone_copies_content_under_a_new_id() {
+    new_test_ext().execute_with(|| {
+        let source = add_cert(1, b"title");
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            source,
+            bounded(b"level"),
+            bounded(b"senior")
+        ));
+
+        System::set_block_number(4);
+        let new_id = next_id(1);
+        assert_ok!(Template::clone_certification(
+            RuntimeOrigin::signed(1),
+            source
+        ));
+        System::assert_last_event(
+            Event::CertificationCloned {
+                source_id: source,
+                new_id,
+            }
+            .into(),
+        );
+
+        assert_ne!(new_id, source);
+        let original = ListOfCertifications::<Test>::get(source).unwrap();
+        let copy = ListOfCertifications::<Test>::get(new_id).unwrap();
+        assert_eq!(copy.title, original.title);
+        assert_eq!(copy.description, original.description);
+        assert_eq!(copy.content_hash, original.content_hash);
+        assert_eq!(copy.kind, original.kind);
+        assert_eq!((copy.created_at, copy.updated_at, copy.version), (4, 4, 0));
+        assert!(!copy.revoked);
+        assert_eq!(
+            Template::metadata_of(&new_id),
+            Template::metadata_of(&source)
+        );
+        assert_eq!(Template::count_of(&1), 2);
+
+        assert_noop!(
+            Template::clone_certification(RuntimeOrigin::signed(2), source),
+            Error::<Test>::NotOwner
+        );
+        for _ in 2..10 {
+            assert_ok!(Template::clone_certification(
+                RuntimeOrigin::signed(1),
+                source
+            ));
+        }
+        assert_noop!(
+            Template::clone_certification(RuntimeOrigin::signed(1), source),
+            Error::<Test>::TooManyCertifications
+        );
+    });
+}
+
+fn merged_from(
+    target: &CertificationIdOf<Test>,
+) -> Vec<(CertificationIdOf<Test>, Vec<u8>, Vec<u8>)> {
+    Template::merged_from(target)
+        .into_iter()
+        .map(|(source, title, description)| (source, title.into_inner(), description.into_inner()))
+        .collect()
+}
+
+#[test]
+fn merge_copies_missing_metadata_and_removes_the_duplicate() {
+    new_test_ext().execute_with(|| {
+        let target = add_cert(1, b"title");
+        let source = add_cert(1, b"First copy");
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            source,
+            bounded(b"level"),
+            bounded(b"senior")
+        ));
+
+        assert_ok!(Template::merge_certifications(
+            RuntimeOrigin::signed(1),
+            target,
+            source
+        ));
+        System::assert_last_event(Event::CertificationsMerged { target, source }.into());
+
+        assert_eq!(
+            Template::metadata_of(&target),
+            vec![(bounded(b"level"), bounded(b"senior"))]
+        );
+        assert_eq!(
+            merged_from(&target),
+            vec![(source, b"First copy".to_vec(), b"description".to_vec())]
+        );
+        assert!(!Template::exists(&source));
+        assert!(Template::metadata_of(&source).is_empty());
+        assert_eq!(
+            CertificationsByOwner::<Test>::get(1).into_inner(),
+            vec![target]
+        );
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+
+        // Existing keys are kept, and each merge is recorded separately.
+        let second = add_cert(1, b"Second copy");
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            second,
+            bounded(b"level"),
+            bounded(b"junior")
+        ));
+        assert_ok!(Template::merge_certifications(
+            RuntimeOrigin::signed(1),
+            target,
+            second
+        ));
+        assert_eq!(
+            Template::metadata_of(&target),
+            vec![(bounded(b"level"), bounded(b"senior"))]
+        );
+        let merged = merged_from(&target);
+        assert_eq!(merged.len(), 2);
+        assert!(merged.contains(&(source, b"First copy".to_vec(), b"description".to_vec())));
+        assert!(merged.contains(&(second, b"Second copy".to_vec(), b"description".to_vec())));
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn merge_rejects_self_foreign_and_overfull_merges() {
+    new_test_ext().execute_with(|| {
+        let target = add_cert(1, b"title");
+        let source = add_cert(1, b"title");
+        let foreign = add_cert(2, b"title");
+
+        assert_noop!(
+            Template::merge_certifications(RuntimeOrigin::signed(1), target, target),
+            Error::<Test>::CannotMergeWithSelf
+        );
+        assert_noop!(
+            Template::merge_certifications(RuntimeOrigin::signed(1), target, foreign),
+            Error::<Test>::NotOwner
+        );
+
+        for key in [b"a", b"b"] {
+            assert_ok!(Template::set_metadata(
+                RuntimeOrigin::signed(1),
+                target,
+                bounded(key),
+                bounded(b"value")
+            ));
+        }
+        assert_ok!(Template::set_metadata(
+            RuntimeOrigin::signed(1),
+            source,
+            bounded(b"c"),
+            bounded(b"value")
+        ));
+        assert_noop!(
+            Template::merge_certifications(RuntimeOrigin::signed(1), target, source),
+            Error::<Test>::TooManyMetadataEntries
+        );
+
+        // Only copied entries count, the merge record is kept apart.
+        assert_ok!(Template::clear_metadata(
+            RuntimeOrigin::signed(1),
+            source,
+            bounded(b"c")
+        ));
+        assert_ok!(Template::merge_certifications(
+            RuntimeOrigin::signed(1),
+            target,
+            source
+        ));
+        assert_eq!(Template::merged_from(&target).len(), 1);
+    });
+}
+
+#[test]
+fn merge_records_are_bounded_and_removed_with_the_target() {
+    new_test_ext().execute_with(|| {
+        let target = add_cert(1, b"title");
+        for title in [b"First copy", b"Other copy"] {
+            let source = add_cert(1, title);
+            assert_ok!(Template::merge_certifications(
+                RuntimeOrigin::signed(1),
+                target,
+                source
+            ));
+        }
+
+        let third = add_cert(1, b"Third copy");
+        assert_noop!(
+            Template::merge_certifications(RuntimeOrigin::signed(1), target, third),
+            Error::<Test>::TooManyMerges
+        );
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            target,
+            None
+        ));
+        assert!(Template::merged_from(&target).is_empty());
+        assert_eq!(MergedFrom::<Test>::iter_keys().count(), 0);
+    });
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn transfer_all_certifications_is_atomic() {
     new_test_ext().execute_with(|| {
-        // Dispatch a signed extrinsic.
-        assert_ok!(Template::do_something(RuntimeOrigin::signed(1), 42));
-        // Read pallet storage and assert an expected result.
-        assert_eq!(Something::<Test>::get().map(|v| v.block_number), Some(42));
+        let ids: Vec<_> = (0..3).map(|_| add_cert(1, b"title")).collect();
+        // Only the last certification to be moved fails.
+        assert_ok!(Template::freeze_certification(
+            RuntimeOrigin::root(),
+            ids[2]
+        ));
+        let nonce = CertificationNonce::<Test>::get();
+
+        assert_noop!(
+            Template::transfer_all_certifications(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::CertificationFrozen
+        );
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), ids);
+        assert!(CertificationsByOwner::<Test>::get(2).is_empty());
+        assert_eq!(CertificationCountByOwner::<Test>::get(1), 3);
+        assert_eq!(CertificationCountByOwner::<Test>::get(2), 0);
+        assert_eq!(CertificationNonce::<Test>::get(), nonce);
+        assert_eq!(CertificationCount::<Test>::get(), 3);
+        assert!(ids.iter().all(|id| Template::owner_of(id) == Some(1)));
+    });
+}
+
+#[test]
+fn instances_have_independent_storage() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_eq!(ListOfCertifications::<Test, Instance1>::iter().count(), 0);
+
+        // The same owner and nonce derive the same id in both registries.
+        assert_ok!(AcademicRegistry::add_certification(
+            RuntimeOrigin::signed(1),
+            b"degree".to_vec(),
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None
+        ));
+        System::assert_last_event(
+            crate::Event::<Test, Instance1>::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 1,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+        assert_eq!(CertificationCount::<Test, Instance1>::get(), 1);
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+        assert_eq!(
+            AcademicRegistry::view(&id).unwrap().title,
+            b"degree".to_vec()
+        );
+        assert_eq!(Template::view(&id).unwrap().title, b"title".to_vec());
+
+        // Removing from one registry leaves the other untouched, and pausing is per instance.
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert!(!Template::exists(&id));
+        assert!(AcademicRegistry::exists(&id));
+        assert_ok!(Template::set_paused(RuntimeOrigin::root(), true));
+        assert_ok!(AcademicRegistry::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert_eq!(CertificationCount::<Test, Instance1>::get(), 0);
+    });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn create_certifications_for_leaves_indexes_consistent() {
+    new_test_ext().execute_with(|| {
+        add_cert(1, b"title");
+        let max = <Test as crate::Config>::MaxCertificationsPerOwner::get();
+        Template::create_certifications_for(&1, max - 1);
+
+        assert_eq!(Template::count_of(&1), max);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).len() as u32, max);
+        assert_eq!(CertificationCount::<Test>::get(), max);
+        assert_ok!(Template::do_try_state());
+
+        // The populated entries behave like regular ones.
+        let last = *CertificationsByOwner::<Test>::get(1).last().unwrap();
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            last,
+            None
+        ));
+        assert_eq!(Template::count_of(&1), max - 1);
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+fn add_with_content_type(
+    description: &[u8],
+    content_type: ContentType,
+) -> DispatchResultWithPostInfo {
+    Template::add_certification(
+        RuntimeOrigin::signed(1),
+        b"title".to_vec(),
+        description.to_vec(),
+        content_type,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn add_with_external_id(who: u64, external_id: &[u8]) -> CertificationIdOf<Test> {
+    let id = next_id(who);
+    assert_ok!(Template::add_certification(
+        RuntimeOrigin::signed(who),
+        b"title".to_vec(),
+        b"description".to_vec(),
+        ContentType::PlainText,
+        CertificationKind::Other,
+        true,
+        Default::default(),
+        None,
+        None,
+        None,
+        None,
+        Some(bounded(external_id))
+    ));
+    id
+}
+
+#[test]
+fn certifications_can_be_found_by_external_id() {
+    new_test_ext().execute_with(|| {
+        let did = bounded(b"did:example:alice");
+        let first = add_with_external_id(1, b"did:example:alice");
+        let second = add_with_external_id(2, b"did:example:alice");
+        add_with_external_id(1, b"did:example:bob");
+        let plain = add_cert(1, b"title");
+
+        assert_eq!(ExternalIds::<Test>::get(first), Some(did.clone()));
+        assert_eq!(ExternalIds::<Test>::get(plain), None);
+        let mut found = Template::certifications_by_external_id(&did);
+        found.sort();
+        let mut expected = vec![first, second];
+        expected.sort();
+        assert_eq!(found, expected);
+        assert!(Template::certifications_by_external_id(&bounded(b"did:example:carol")).is_empty());
+    });
+}
+
+#[test]
+fn removal_clears_the_external_id() {
+    new_test_ext().execute_with(|| {
+        let did = bounded(b"did:example:alice");
+        let id = add_with_external_id(1, b"did:example:alice");
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert!(!ExternalIds::<Test>::contains_key(id));
+        assert!(Template::certifications_by_external_id(&did).is_empty());
+    });
+}
+
+#[test]
+fn invalid_content_is_rejected_before_storage_access() {
+    new_test_ext().execute_with(|| {
+        let error = Template::add_certification(
+            RuntimeOrigin::signed(1),
+            vec![b'a'; 33],
+            b"description".to_vec(),
+            ContentType::PlainText,
+            CertificationKind::Other,
+            true,
+            Default::default(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(error.error, Error::<Test>::TitleTooLong.into());
+        assert_eq!(
+            error.post_info.actual_weight,
+            Some(Weight::from_parts(10_000, 0))
+        );
+
+        let error = add_with_content_type(b"not a uri", ContentType::Uri).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::InvalidUri.into());
+        assert_eq!(
+            error.post_info.actual_weight,
+            Some(Weight::from_parts(10_000, 0))
+        );
+
+        // Later failures are charged in full.
+        assert_ok!(Template::set_paused(RuntimeOrigin::root(), true));
+        let error = add_with_content_type(b"description", ContentType::PlainText).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::PalletPaused.into());
+        assert_eq!(error.post_info.actual_weight, None);
+    });
+}
+
+#[test]
+fn content_type_is_set_at_creation() {
+    new_test_ext().execute_with(|| {
+        for (description, content_type) in [
+            (&b"plain words"[..], ContentType::PlainText),
+            (b"# Heading\n*emphasis*", ContentType::Markdown),
+            (
+                b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+                ContentType::Uri,
+            ),
+            (b"https://example.org/diploma.pdf", ContentType::Uri),
+        ] {
+            let id = next_id(1);
+            assert_ok!(add_with_content_type(description, content_type));
+            assert_eq!(
+                ListOfCertifications::<Test>::get(id).unwrap().content_type,
+                content_type
+            );
+        }
+        assert_eq!(
+            ListOfCertifications::<Test>::get(add_cert(1, b"title"))
+                .unwrap()
+                .content_type,
+            ContentType::default()
+        );
+        assert_eq!(ContentType::default(), ContentType::PlainText);
+    });
+}
+
+#[test]
+fn invalid_uri_is_rejected() {
+    new_test_ext().execute_with(|| {
+        for description in [
+            &b"example.org/diploma"[..],
+            b"://example.org",
+            b"1http://example.org",
+            b"https://example.org/caf\xc3\xa9",
+            b"https://example.org/a b",
+        ] {
+            assert_noop!(
+                add_with_content_type(description, ContentType::Uri),
+                Error::<Test>::InvalidUri
+            );
+        }
+    });
+}
+
+#[test]
+fn owner_can_change_the_content_type() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_noop!(
+            Template::set_content_type(RuntimeOrigin::signed(2), id, ContentType::Markdown),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(Template::set_content_type(
+            RuntimeOrigin::signed(1),
+            id,
+            ContentType::Markdown
+        ));
+        System::assert_last_event(
+            Event::ContentTypeSet {
+                certification_id: id,
+                content_type: ContentType::Markdown,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ListOfCertifications::<Test>::get(id).unwrap().content_type,
+            ContentType::Markdown
+        );
+
+        // "description" has no scheme, so it cannot become a URI.
+        assert_noop!(
+            Template::set_content_type(RuntimeOrigin::signed(1), id, ContentType::Uri),
+            Error::<Test>::InvalidUri
+        );
+        assert_ok!(Template::update_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"title".to_vec(),
+            b"mailto:registrar@example.org".to_vec(),
+            0,
+            None
+        ));
+        assert_ok!(Template::set_content_type(
+            RuntimeOrigin::signed(1),
+            id,
+            ContentType::Uri
+        ));
+
+        // Updates must keep a URI description valid.
+        assert_noop!(
+            Template::update_certification(
+                RuntimeOrigin::signed(1),
+                id,
+                b"title".to_vec(),
+                b"not a uri".to_vec(),
+                1,
+                None
+            ),
+            Error::<Test>::InvalidUri
+        );
+    });
+}
+
+#[test]
+fn last_created_tracks_the_latest_add_per_account() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Template::last_created(&1), None);
+
+        let first = add_cert(1, b"first");
+        assert_eq!(Template::last_created(&1), Some(first));
+        let other = add_cert(2, b"other");
+        let second = add_cert(1, b"second");
+        assert_eq!(Template::last_created(&1), Some(second));
+        assert_eq!(Template::last_created(&2), Some(other));
+
+        // Certifications created through another pallet count too.
+        let issued = next_id(2);
+        assert_ok!(Issuer::issue_for(
+            RuntimeOrigin::signed(1),
+            2,
+            b"issued".to_vec(),
+            b"description".to_vec()
+        ));
+        assert_eq!(Template::last_created(&2), Some(issued));
+        assert_eq!(Template::last_created(&1), Some(second));
+    });
+}
+
+fn offchain_index(block: u64) -> Option<Vec<CertificationIdOf<Test>>> {
+    StorageValueRef::persistent(&Template::offchain_index_key(block))
+        .get()
+        .unwrap()
+}
+
+#[test]
+fn offchain_worker_indexes_created_certifications() {
+    let (offchain, _offchain_state) = TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+
+    ext.execute_with(|| {
+        let first = add_cert(1, b"first");
+        Template::offchain_worker(1);
+        assert_eq!(offchain_index(1), Some(vec![first]));
+
+        // Blocks missed while the node was offline are caught up on the next run.
+        System::set_block_number(2);
+        let second = add_cert(1, b"second");
+        System::set_block_number(3);
+        let third = add_cert(2, b"third");
+        Template::offchain_worker(3);
+        assert_eq!(offchain_index(2), Some(vec![second]));
+        assert_eq!(offchain_index(3), Some(vec![third]));
+
+        // At most `MAX_OCW_INDEXED_BLOCKS` blocks are indexed per run.
+        let now = 3 + 2 * MAX_OCW_INDEXED_BLOCKS as u64;
+        System::set_block_number(now);
+        let late = add_cert(1, b"late");
+        Template::offchain_worker(now);
+        assert_eq!(offchain_index(now), None);
+        Template::offchain_worker(now);
+        assert_eq!(offchain_index(now), Some(vec![late]));
+        // Blocks without certifications are not written.
+        assert_eq!(offchain_index(now - 1), None);
+    });
+}
+
+#[test]
+fn description_chunks_are_reassembled_in_order() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        assert_eq!(
+            Template::reassembled_description(&id),
+            Some(b"description".to_vec())
+        );
+
+        assert_noop!(
+            Template::append_description_chunk(RuntimeOrigin::signed(2), id, 0, bounded(b" one")),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            Template::append_description_chunk(RuntimeOrigin::signed(1), id, 1, bounded(b" two")),
+            Error::<Test>::ChunkIndexGap
+        );
+        for (index, chunk) in [b" one", b" two"].into_iter().enumerate() {
+            assert_ok!(Template::append_description_chunk(
+                RuntimeOrigin::signed(1),
+                id,
+                index as u16,
+                bounded(chunk)
+            ));
+        }
+        System::assert_last_event(
+            Event::DescriptionChunkAppended {
+                certification_id: id,
+                index: 1,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Template::reassembled_description(&id),
+            Some(b"description one two".to_vec())
+        );
+
+        // An existing chunk can be replaced.
+        assert_ok!(Template::append_description_chunk(
+            RuntimeOrigin::signed(1),
+            id,
+            0,
+            bounded(b" uno")
+        ));
+        assert_eq!(
+            Template::reassembled_description(&id),
+            Some(b"description uno two".to_vec())
+        );
+        assert_eq!(Template::reassembled_description(&next_id(1)), None);
+    });
+}
+
+#[test]
+fn description_chunks_are_bounded_and_removed_with_the_certification() {
+    new_test_ext().execute_with(|| {
+        let id = add_cert(1, b"title");
+        for index in 0..3 {
+            assert_ok!(Template::append_description_chunk(
+                RuntimeOrigin::signed(1),
+                id,
+                index,
+                bounded(b"chunk")
+            ));
+        }
+        assert_noop!(
+            Template::append_description_chunk(RuntimeOrigin::signed(1), id, 3, bounded(b"chunk")),
+            Error::<Test>::TooManyChunks
+        );
+
+        assert_ok!(Template::remove_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            None
+        ));
+        assert_eq!(DescriptionChunks::<Test>::iter_prefix(id).count(), 0);
+    });
+}
+
+#[test]
+fn upsert_creates_a_missing_certification_under_the_given_id() {
+    new_test_ext().execute_with(|| {
+        let id = next_id(1);
+        assert_ok!(Template::upsert_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"title".to_vec(),
+            b"description".to_vec()
+        ));
+        System::assert_last_event(
+            Event::CertificationStored {
+                who: 1,
+                issuer: 1,
+                holder: 1,
+                certification_id: id,
+                created_at: 1,
+                schema_version: 2,
+                title: None,
+                description: None,
+            }
+            .into(),
+        );
+        let stored = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(stored.owner_id, 1);
+        assert_eq!(stored.version, 0);
+        assert_eq!(CertificationsByOwner::<Test>::get(1).into_inner(), vec![id]);
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+        assert_eq!(Template::last_created(&1), Some(id));
+
+        // The content-derived id is accepted as well.
+        let id = Template::content_id(&1, b"other", b"description");
+        assert_ok!(Template::upsert_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"other".to_vec(),
+            b"description".to_vec()
+        ));
+        assert!(ListOfCertifications::<Test>::contains_key(id));
+    });
+}
+
+#[test]
+fn upsert_cannot_create_under_an_id_the_caller_does_not_derive() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Template::upsert_certification(
+                RuntimeOrigin::signed(1),
+                CertificationId(H256::repeat_byte(9)),
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+
+        // Taking the id of another account's next certification is rejected, so that
+        // account can still create it.
+        let victim = next_id(2);
+        assert_noop!(
+            Template::upsert_certification(
+                RuntimeOrigin::signed(1),
+                victim,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::CertificationNotFound
+        );
+        assert_eq!(add_cert(2, b"title"), victim);
     });
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn upsert_updates_an_existing_owned_certification() {
     new_test_ext().execute_with(|| {
-        // Ensure the expected error is thrown when no value is present.
+        let id = add_cert(1, b"title");
+        assert_ok!(Template::upsert_certification(
+            RuntimeOrigin::signed(1),
+            id,
+            b"new title".to_vec(),
+            b"new description".to_vec()
+        ));
+        System::assert_last_event(
+            Event::CertificationUpdated {
+                who: 1,
+                certification_id: id,
+                updated_at: 1,
+            }
+            .into(),
+        );
+        let stored = ListOfCertifications::<Test>::get(id).unwrap();
+        assert_eq!(stored.title.to_vec(), b"new title".to_vec());
+        assert_eq!(stored.version, 1);
+        assert_eq!(CertificationCount::<Test>::get(), 1);
+
+        // Someone else's certification is neither updated nor replaced.
         assert_noop!(
-            Template::cause_error(RuntimeOrigin::signed(1)),
-            Error::<Test>::NoneValue
+            Template::upsert_certification(
+                RuntimeOrigin::signed(2),
+                id,
+                b"title".to_vec(),
+                b"description".to_vec()
+            ),
+            Error::<Test>::NotOwner
         );
     });
 }
@@ -1,29 +1,19 @@
 
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_certification
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `_`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! NOT GENERATED: these figures are estimates. `remove_certification` and
+//! `process_expirations` come from an earlier benchmark run, adjusted by hand for the current
+//! benchmark components. `add_certification`, `update_certification` and
+//! `transfer_all_certifications` are derived from the storage accesses of their benchmarks and
+//! the execution time of comparable calls. None of them have been measured for the current
+//! benchmarks and they must be regenerated on reference hardware before production use:
+//!
+//! cargo build --release -p parachain-template-runtime --features runtime-benchmarks
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/parachain-template-runtime/parachain_template_runtime.compact.compressed.wasm \
+//!     --pallet pallet_certification --extrinsic "*" \
+//!     --steps 50 --repeat 20 \
+//!     --output pallets/certification/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,13 +22,16 @@
 use frame::{deps::frame_support::weights::constants::RocksDbWeight, prelude::*};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_certification.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn add_certification(m: u32, ) -> Weight;
+	fn update_certification() -> Weight;
+	fn process_expirations(s: u32, n: u32, ) -> Weight;
+	fn remove_certification(n: u32, ) -> Weight;
+	fn transfer_all_certifications(n: u32, ) -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for pallet_certification using the Substrate node and recommended hardware.
 #[cfg_attr(
     not(feature = "std"),
     deprecated(
@@ -47,50 +40,300 @@ pub trait WeightInfo {
 )]
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template SeenRequests (r:1 w:1)
+	/// Proof: Template SeenRequests (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Template LastCreatedAt (r:1 w:1)
+	/// Proof: Template LastCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template CreationWindow (r:1 w:1)
+	/// Proof: Template CreationWindow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:1 w:1)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template CertificationNonce (r:1 w:1)
+	/// Proof: Template CertificationNonce (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:2 w:2)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:1 w:1)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:1 w:1)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCount (r:1 w:1)
+	/// Proof: Template CertificationCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Template HistoryHead (r:1 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Template SelfIssuedCount (r:1 w:1)
+	/// Proof: Template SelfIssuedCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Template TitlesByOwner (r:0 w:1)
+	/// Storage: Template CertificationsByBlock (r:0 w:1)
+	/// Storage: Template CertificationsByKind (r:0 w:1)
+	/// Storage: Template CertificationDeposits (r:0 w:1)
+	/// Storage: Template CertificationRequests (r:0 w:1)
+	/// Storage: Template ExternalIds (r:0 w:1)
+	/// Storage: Template CertificationsByExternalId (r:0 w:1)
+	/// Storage: Template Metadata (r:0 w:16)
+	/// Proof: Template Metadata (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 16]`.
+	fn add_certification(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `580`
+		//  Estimated: `6682`
+		Weight::from_parts(96_400_000, 6682)
+			.saturating_add(Weight::from_parts(2_150_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:1 w:1)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:1 w:2)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByBlock (r:0 w:2)
+	/// Storage: Template TitlesByOwner (r:0 w:2)
+	/// Storage: Template HistoryHead (r:1 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	fn update_certification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3836`
+		Weight::from_parts(41_200_000, 3836)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: Template ExpiryCheckCursor (r:1 w:1)
+	/// Proof: Template ExpiryCheckCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:20 w:20)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 20]`.
+	/// The range of component `n` is `[0, 20]`.
+	fn process_expirations(s: u32, n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `0 + s * (185 ±0) + n * (12 ±0)`
+		//  Estimated: `1517 + s * (2846 ±0)`
+		Weight::from_parts(5_021_000, 1517)
+			.saturating_add(Weight::from_parts(3_819_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(11_803_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2846).saturating_mul(s.into()))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:1 w:1)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:1 w:1)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:1 w:1)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template Metadata (r:1 w:0)
+	/// Proof: Template Metadata (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: Template DescriptionChunks (r:1 w:0)
+	/// Proof: Template DescriptionChunks (max_values: None, max_size: Some(1076), added: 3551, mode: MaxEncodedLen)
+	/// Storage: Template CertificationDeposits (r:1 w:0)
+	/// Proof: Template CertificationDeposits (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCount (r:1 w:1)
+	/// Proof: Template CertificationCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_certification(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + n * (32 ±0)`
+		//  Estimated: `4541`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(38_614_000, 4541)
+			// Standard Error: 4_012
+			.saturating_add(Weight::from_parts(391_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:2 w:2)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:2 w:2)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:50 w:50)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:50 w:100)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template SelfIssuedCount (r:2 w:2)
+	/// Proof: Template SelfIssuedCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template HistoryHead (r:50 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:50 w:50)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Template TitlesByOwner (r:0 w:100)
+	/// Storage: Template PendingTransfers (r:0 w:50)
+	/// The range of component `n` is `[0, 50]`.
+	fn transfer_all_certifications(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240 + n * (412 ±0)`
+		//  Estimated: `6680 + n * (2846 ±0)`
+		Weight::from_parts(14_300_000, 6680)
+			.saturating_add(Weight::from_parts(27_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2846).saturating_mul(n.into()))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template SeenRequests (r:1 w:1)
+	/// Proof: Template SeenRequests (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Template LastCreatedAt (r:1 w:1)
+	/// Proof: Template LastCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template CreationWindow (r:1 w:1)
+	/// Proof: Template CreationWindow (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:1 w:1)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template CertificationNonce (r:1 w:1)
+	/// Proof: Template CertificationNonce (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:2 w:2)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:1 w:1)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:1 w:1)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCount (r:1 w:1)
+	/// Proof: Template CertificationCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Template HistoryHead (r:1 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Template SelfIssuedCount (r:1 w:1)
+	/// Proof: Template SelfIssuedCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Template TitlesByOwner (r:0 w:1)
+	/// Storage: Template CertificationsByBlock (r:0 w:1)
+	/// Storage: Template CertificationsByKind (r:0 w:1)
+	/// Storage: Template CertificationDeposits (r:0 w:1)
+	/// Storage: Template CertificationRequests (r:0 w:1)
+	/// Storage: Template ExternalIds (r:0 w:1)
+	/// Storage: Template CertificationsByExternalId (r:0 w:1)
+	/// Storage: Template Metadata (r:0 w:16)
+	/// Proof: Template Metadata (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 16]`.
+	fn add_certification(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `580`
+		//  Estimated: `6682`
+		Weight::from_parts(96_400_000, 6682)
+			.saturating_add(Weight::from_parts(2_150_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:1 w:1)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:1 w:2)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByBlock (r:0 w:2)
+	/// Storage: Template TitlesByOwner (r:0 w:2)
+	/// Storage: Template HistoryHead (r:1 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	fn update_certification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3836`
+		Weight::from_parts(41_200_000, 3836)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: Template ExpiryCheckCursor (r:1 w:1)
+	/// Proof: Template ExpiryCheckCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:20 w:20)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 20]`.
+	/// The range of component `n` is `[0, 20]`.
+	fn process_expirations(s: u32, n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `0 + s * (185 ±0) + n * (12 ±0)`
+		//  Estimated: `1517 + s * (2846 ±0)`
+		Weight::from_parts(5_021_000, 1517)
+			.saturating_add(Weight::from_parts(3_819_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(11_803_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2846).saturating_mul(s.into()))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:1 w:1)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:1 w:1)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:1 w:1)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template Metadata (r:1 w:0)
+	/// Proof: Template Metadata (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: Template DescriptionChunks (r:1 w:0)
+	/// Proof: Template DescriptionChunks (max_values: None, max_size: Some(1076), added: 3551, mode: MaxEncodedLen)
+	/// Storage: Template CertificationDeposits (r:1 w:0)
+	/// Proof: Template CertificationDeposits (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCount (r:1 w:1)
+	/// Proof: Template CertificationCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_certification(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + n * (32 ±0)`
+		//  Estimated: `4541`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(38_614_000, 4541)
+			// Standard Error: 4_012
+			.saturating_add(Weight::from_parts(391_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Template Paused (r:1 w:0)
+	/// Proof: Template Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Template CertificationsByOwner (r:2 w:2)
+	/// Proof: Template CertificationsByOwner (max_values: None, max_size: Some(370), added: 2845, mode: MaxEncodedLen)
+	/// Storage: Template CertificationCountByOwner (r:2 w:2)
+	/// Proof: Template CertificationCountByOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template ListOfCertifications (r:50 w:50)
+	/// Proof: Template ListOfCertifications (max_values: None, max_size: Some(371), added: 2846, mode: MaxEncodedLen)
+	/// Storage: Template TitleIndex (r:50 w:100)
+	/// Proof: Template TitleIndex (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Template SelfIssuedCount (r:2 w:2)
+	/// Proof: Template SelfIssuedCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template HistoryHead (r:50 w:0)
+	/// Proof: Template HistoryHead (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Template History (r:50 w:50)
+	/// Proof: Template History (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Template TitlesByOwner (r:0 w:100)
+	/// Storage: Template PendingTransfers (r:0 w:50)
+	/// The range of component `n` is `[0, 50]`.
+	fn transfer_all_certifications(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240 + n * (412 ±0)`
+		//  Estimated: `6680 + n * (2846 ±0)`
+		Weight::from_parts(14_300_000, 6680)
+			.saturating_add(Weight::from_parts(27_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2846).saturating_mul(n.into()))
 	}
 }
@@ -45,9 +45,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, BlockNumber, CertificationPallet, ConsensusHook, Executive, Hash,
+    InherentDataExt, Nonce, ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig,
+    SessionKeys, System, TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 // we move some impls outside so we can easily use them with `docify`.
@@ -219,6 +219,44 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_certification::runtime_api::CertificationApi<Block, AccountId, Hash, BlockNumber>
+        for Runtime
+    {
+        fn get_certification(
+            certification_id: Hash,
+            caller: Option<AccountId>,
+        ) -> Option<pallet_certification::CertificationDetails<AccountId, Hash, BlockNumber>> {
+            CertificationPallet::get_certification(&certification_id.into(), caller.as_ref())
+        }
+
+        fn view(
+            certification_id: Hash,
+            caller: Option<AccountId>,
+        ) -> Option<pallet_certification::CertificationView<AccountId, Hash>> {
+            CertificationPallet::view_as(&certification_id.into(), caller.as_ref())
+        }
+
+        fn certifications_of_many(
+            owners: Vec<AccountId>,
+        ) -> Vec<(AccountId, Vec<pallet_certification::CertificationView<AccountId, Hash>>)> {
+            CertificationPallet::certifications_of_many(owners)
+        }
+
+        fn certification_headers_of(
+            owner: AccountId,
+        ) -> Vec<pallet_certification::CertificationHeader<AccountId, Hash>> {
+            CertificationPallet::certification_headers_of(&owner)
+        }
+
+        fn stats() -> pallet_certification::CertificationStats {
+            CertificationPallet::stats()
+        }
+
+        fn limits() -> pallet_certification::CertificationLimits {
+            CertificationPallet::limits()
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -37,7 +37,8 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf,
+        ConstBool, ConstU16, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin,
+        VariantCountOf,
     },
     weights::{ConstantMultiplier, Weight},
     PalletId,
@@ -52,7 +53,7 @@ use polkadot_runtime_common::{
     xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::Perbill;
+use sp_runtime::{traits::Verify, Perbill};
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
 
@@ -62,8 +63,9 @@ use super::{
     AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
     MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
     RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-    System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS,
-    MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    Signature, System, Timestamp, UncheckedExtrinsic, WeightToFee, XcmpQueue,
+    AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
+    MICRO_UNIT, MILLI_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
 };
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
@@ -320,6 +322,79 @@ impl pallet_collator_selection::Config for Runtime {
 impl pallet_certification::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_certification::weights::SubstrateWeight<Runtime>;
+    type MaxCertificationsPerOwner = ConstU32<100>;
+    type MaxTransferPerCall = ConstU32<50>;
+    type MaxCreatesPerWindow = ConstU32<10>;
+    type WindowLength = ConstU32<100>;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type CurrentSchemaVersion = ConstU16<1>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type Time = Timestamp;
+    type ContentHasher = <Runtime as frame_system::Config>::Hashing;
+    type CreationCooldown = ConstU32<0>;
+    type HideExistence = ConstBool<false>;
+    type ArchiveRemoved = ConstBool<false>;
+    type MaxTotalCertifications = ConstU32<100_000>;
+    type MaxMetaKeyLen = ConstU32<32>;
+    type MaxMetaValueLen = ConstU32<256>;
+    type MaxMetadataEntries = ConstU32<16>;
+    type EmitContentInEvents = ConstBool<false>;
+    type MaxOperators = ConstU32<10>;
+    type ExpiryWarningWindow = ConstU32<DAYS>;
+    type MaxOffchainScan = ConstU32<100>;
+    type MaxBatchSize = ConstU32<50>;
+    type MaxTitleLength = ConstU32<128>;
+    type MinTitleLength = ConstU32<1>;
+    type MaxDescriptionLength = ConstU32<1024>;
+    type SortOwnerIndex = ConstBool<false>;
+    type Currency = Balances;
+    type CertificationDeposit = CertificationDeposits;
+    type EnforceUniqueTitlePerOwner = ConstBool<false>;
+    type ContentAddressed = ConstBool<false>;
+    type TombstoneRetention = ConstU32<{ 30 * DAYS }>;
+    type MaxExpiryChecksPerBlock = ConstU32<20>;
+    type MaxReasonLen = ConstU32<128>;
+    type MaxChunkLen = ConstU32<1024>;
+    type MaxChunks = ConstU32<16>;
+    type MaxOwnersPerQuery = ConstU32<32>;
+    type ResetCreatedOnUpdate = ConstBool<false>;
+    type EmitIndexEvents = ConstBool<false>;
+    type ReclaimGracePeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxExternalIdLen = ConstU32<128>;
+    type MaxEditors = ConstU32<16>;
+    type MaxHistoryEntries = ConstU32<128>;
+    type MaxMergedFrom = ConstU32<16>;
+}
+
+/// Deposits reserved for each kind of certification.
+pub struct CertificationDeposits;
+impl pallet_certification::KindDeposit<Balance> for CertificationDeposits {
+    fn deposit_for(kind: &pallet_certification::CertificationKind) -> Balance {
+        use pallet_certification::CertificationKind::*;
+        match kind {
+            Professional => 10 * MILLI_UNIT,
+            Academic => 5 * MILLI_UNIT,
+            SelfDeclared | Other => MILLI_UNIT,
+        }
+    }
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateInherent<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    fn create_inherent(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
 }
 
 /// Configure the pallet template in pallets/template.